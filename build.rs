@@ -0,0 +1,161 @@
+//! Generates the OUI table of `eui::vendor::IEEE_SUBSET` from the IEEE MA-L
+//! CSV with the `oui-table-codegen` feature. Does nothing otherwise.
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const CSV_VAR: &str = "EUI_OUI_CSV";
+
+fn main() {
+    if env::var_os("CARGO_FEATURE_OUI_TABLE_CODEGEN").is_none() {
+        return;
+    }
+
+    println!("cargo:rerun-if-env-changed={}", CSV_VAR);
+
+    let path = env::var(CSV_VAR).unwrap_or_else(|_| {
+        panic!(
+            "The oui-table-codegen feature needs {} set to the absolute path of the \
+             IEEE MA-L CSV (oui.csv)",
+            CSV_VAR
+        )
+    });
+
+    println!("cargo:rerun-if-changed={}", path);
+
+    let csv = fs::read_to_string(&path)
+        .unwrap_or_else(|error| panic!("Cannot read {} {:?}: {}", CSV_VAR, path, error));
+    let table =
+        parse_csv(&csv).unwrap_or_else(|error| panic!("Invalid {} {:?}: {}", CSV_VAR, path, error));
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+
+    fs::write(Path::new(&out_dir).join("oui_table.rs"), generate(&table))
+        .expect("Cannot write the generated OUI table");
+}
+
+/// Fields of one CSV record, with quotes removed and `""` unescaped.
+fn split_record(line: &str) -> Result<Vec<String>, &'static str> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(String::new()),
+            c => fields.last_mut().unwrap().push(c),
+        }
+    }
+
+    if quoted {
+        return Err("unterminated quote");
+    }
+
+    Ok(fields)
+}
+
+/// MA-L assignments by OUI. MA-M and MA-S rows are skipped, as the table
+/// holds whole OUIs only.
+fn parse_csv(csv: &str) -> Result<BTreeMap<[u8; 3], String>, String> {
+    let mut lines = csv.lines().enumerate();
+    let mut table = BTreeMap::new();
+
+    match lines.next() {
+        Some((_, header))
+            if header
+                .trim_start_matches('\u{feff}')
+                .starts_with("Registry,Assignment,Organization Name") => {}
+        _ => return Err("expected header `Registry,Assignment,Organization Name,...`".into()),
+    }
+
+    for (index, line) in lines {
+        let line_number = index + 1;
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let fields =
+            split_record(line).map_err(|error| format!("line {}: {}", line_number, error))?;
+
+        if fields.len() < 3 {
+            return Err(format!("line {}: expected at least 3 fields", line_number));
+        }
+
+        if fields[0] != "MA-L" {
+            continue;
+        }
+
+        let assignment = &fields[1];
+        let value = u32::from_str_radix(assignment, 16)
+            .ok()
+            .filter(|_| assignment.len() == 6)
+            .ok_or_else(|| format!("line {}: invalid assignment {:?}", line_number, assignment))?;
+        let [_, o1, o2, o3] = value.to_be_bytes();
+        let name = fields[2].trim();
+
+        if name.is_empty() {
+            return Err(format!("line {}: empty organization name", line_number));
+        }
+
+        if table.insert([o1, o2, o3], name.to_string()).is_some() {
+            return Err(format!(
+                "line {}: duplicate assignment {}",
+                line_number, assignment
+            ));
+        }
+    }
+
+    if table.is_empty() {
+        return Err("no MA-L assignments".into());
+    }
+
+    Ok(table)
+}
+
+/// Same `NAMES`, `OUIS` and `VENDORS` constants as written by hand in
+/// `src/vendor.rs`.
+fn generate(table: &BTreeMap<[u8; 3], String>) -> String {
+    let mut names: Vec<&str> = Vec::new();
+    let mut indices = BTreeMap::new();
+    let mut vendors = Vec::new();
+
+    for name in table.values() {
+        let index = *indices.entry(name.as_str()).or_insert_with(|| {
+            names.push(name);
+            names.len() - 1
+        });
+
+        vendors.push(u16::try_from(index).expect("More than 65536 organization names"));
+    }
+
+    let mut out = String::from("// Generated by build.rs from the IEEE MA-L CSV.\n\n");
+
+    writeln!(out, "const NAMES: [&str; {}] = [", names.len()).unwrap();
+    for name in &names {
+        writeln!(out, "    {:?},", name).unwrap();
+    }
+    writeln!(out, "];\n").unwrap();
+
+    writeln!(out, "const OUIS: [[u8; 3]; {}] = [", table.len()).unwrap();
+    for [o1, o2, o3] in table.keys() {
+        writeln!(out, "    [0x{:02X}, 0x{:02X}, 0x{:02X}],", o1, o2, o3).unwrap();
+    }
+    writeln!(out, "];\n").unwrap();
+
+    writeln!(out, "const VENDORS: [u16; {}] = [", vendors.len()).unwrap();
+    for vendor in &vendors {
+        writeln!(out, "    {},", vendor).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+
+    out
+}
@@ -0,0 +1,28 @@
+#![no_main]
+
+use eui::{Eui48, Eui64, LinkLayerAddr};
+use libfuzzer_sys::fuzz_target;
+use std::convert::TryFrom;
+
+fuzz_target!(|data: &[u8]| {
+    let eui48 = Eui48::parse_ascii(data);
+    let eui64 = Eui64::parse_ascii(data);
+
+    if let Ok(input) = std::str::from_utf8(data) {
+        assert_eq!(Eui48::try_from(input), eui48);
+        assert_eq!(Eui64::try_from(input), eui64);
+
+        if let Ok(eui48) = eui48 {
+            assert_eq!(Eui48::parse_lenient(input), Ok(eui48));
+        }
+
+        let _ = LinkLayerAddr::try_from(input);
+        let _ = Eui64::parse_lenient(input);
+    }
+
+    for error in [eui48.err(), eui64.err()].iter().flatten() {
+        if let Some(position) = error.position() {
+            assert!(position < data.len());
+        }
+    }
+});
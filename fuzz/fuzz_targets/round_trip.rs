@@ -0,0 +1,27 @@
+#![no_main]
+
+use eui::{Case, Eui48, Eui64, EuiFormat};
+use libfuzzer_sys::fuzz_target;
+use std::convert::TryFrom;
+
+const FORMATS: [EuiFormat; 4] = [
+    EuiFormat::Colon,
+    EuiFormat::Hyphen,
+    EuiFormat::CiscoDot,
+    EuiFormat::Bare,
+];
+
+fuzz_target!(|input: (Eui48, Eui64, bool)| {
+    let (eui48, eui64, upper) = input;
+    let case = if upper { Case::Upper } else { Case::Lower };
+
+    for &format in FORMATS.iter() {
+        let string = eui48.format(format, case);
+        assert_eq!(Eui48::try_from(string.as_str()), Ok(eui48));
+        assert_eq!(Eui48::parse_lenient(&string), Ok(eui48));
+
+        let string = eui64.format(format, case);
+        assert_eq!(Eui64::try_from(string.as_str()), Ok(eui64));
+        assert_eq!(Eui64::parse_lenient(&string), Ok(eui64));
+    }
+});
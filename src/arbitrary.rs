@@ -0,0 +1,72 @@
+use crate::{Eui, Eui60, LinkLayerAddr, Oui};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a, const N: usize> Arbitrary<'a> for Eui<N> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Eui::from_bytes(u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; N]>::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Eui60 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Eui60::new(u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u64::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Oui {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Oui(u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; 3]>::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for LinkLayerAddr {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        if u.arbitrary()? {
+            Ok(LinkLayerAddr::Eui64(u.arbitrary()?))
+        } else {
+            Ok(LinkLayerAddr::Eui48(u.arbitrary()?))
+        }
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(bool::size_hint(depth), (6, Some(8)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui64, LinkLayerAddr};
+    use arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn test_arbitrary() {
+        let data = [0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef, 0x00, 0x00];
+        let mut u = Unstructured::new(&data);
+
+        assert_eq!(
+            Eui48::arbitrary(&mut u).unwrap(),
+            Eui48::from(85204980412143)
+        );
+        assert_eq!(Eui48::size_hint(0), (6, Some(6)));
+        assert_eq!(Eui64::size_hint(0), (8, Some(8)));
+
+        let data = [0x01, 0x4d, 0x7e, 0x54, 0x00, 0x00, 0x97, 0x2e, 0xef];
+
+        assert_eq!(
+            LinkLayerAddr::arbitrary(&mut Unstructured::new(&data)).unwrap(),
+            LinkLayerAddr::Eui64(Eui64::from(5583992946972634863))
+        );
+    }
+}
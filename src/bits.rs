@@ -0,0 +1,174 @@
+use crate::Eui;
+use core::iter::FusedIterator;
+
+// Bits and nibbles are numbered most significant first, as written, so bit 7
+// is the I/G bit and bit 6 the U/L bit. Ethernet puts the bits of every octet
+// on the wire in the opposite order.
+
+const fn get_bit(bytes: &[u8], index: usize) -> bool {
+    bytes[index / 8] & (0x80 >> (index % 8)) != 0
+}
+
+const fn set_bit(bytes: &mut [u8], index: usize, value: bool) {
+    let mask = 0x80 >> (index % 8);
+
+    if value {
+        bytes[index / 8] |= mask;
+    } else {
+        bytes[index / 8] &= !mask;
+    }
+}
+
+const fn nibble(bytes: &[u8], index: usize) -> u8 {
+    if index & 1 == 0 {
+        bytes[index / 2] >> 4
+    } else {
+        bytes[index / 2] & 0xf
+    }
+}
+
+/// Iterator over the bits of an address, most significant first, created by
+/// `bits()` on the address types.
+#[derive(Clone, Debug)]
+pub struct Bits<'a> {
+    bytes: &'a [u8],
+    front: usize,
+    back: usize,
+}
+
+impl<'a> Bits<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Bits {
+            bytes,
+            front: 0,
+            back: bytes.len() * 8,
+        }
+    }
+}
+
+impl Iterator for Bits<'_> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.front += 1;
+
+        Some(get_bit(self.bytes, self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Bits<'_> {
+    fn next_back(&mut self) -> Option<bool> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+
+        Some(get_bit(self.bytes, self.back))
+    }
+}
+
+impl ExactSizeIterator for Bits<'_> {}
+
+impl FusedIterator for Bits<'_> {}
+
+impl<const N: usize> Eui<N> {
+    /// Bit `index` counted from the most significant bit of the first octet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is `8 * N` or more, e.g. 48 for an [`Eui48`](crate::Eui48).
+    #[inline]
+    pub const fn get_bit(&self, index: usize) -> bool {
+        get_bit(&self.0, index)
+    }
+
+    /// Sets bit `index`, numbered as in [`get_bit`](Eui::get_bit).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is `8 * N` or more.
+    #[inline]
+    pub const fn set_bit(&mut self, index: usize, value: bool) {
+        set_bit(&mut self.0, index, value)
+    }
+
+    /// Hex digit `index` as written, e.g. `0xD` for index 1 of
+    /// `4D-7E-54-97-2E-EF`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is `2 * N` or more.
+    #[inline]
+    pub const fn nibble(&self, index: usize) -> u8 {
+        nibble(&self.0, index)
+    }
+
+    pub fn bits(&self) -> Bits<'_> {
+        Bits::new(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui64};
+
+    #[test]
+    fn test_eui48_get_and_set_bit() {
+        let mut eui48 = Eui48::from(85204980412143);
+
+        assert!(eui48.get_bit(7));
+        assert!(!eui48.get_bit(6));
+        assert!(eui48.get_bit(47));
+
+        eui48.set_bit(6, true);
+        eui48.set_bit(47, false);
+
+        assert_eq!(eui48, Eui48::from(0x4F7E54972EEE));
+    }
+
+    #[test]
+    fn test_nibble() {
+        let eui48 = Eui48::from(85204980412143);
+        let eui64 = Eui64::from(5583992946972634863);
+
+        assert_eq!(eui48.nibble(0), 0x4);
+        assert_eq!(eui48.nibble(1), 0xd);
+        assert_eq!(eui48.nibble(11), 0xf);
+        assert_eq!(eui64.nibble(6), 0x0);
+        assert_eq!(eui64.nibble(15), 0xf);
+    }
+
+    #[test]
+    fn test_bits() {
+        let eui64 = Eui64::from(0x8000000000000001);
+
+        assert_eq!(eui64.bits().len(), 64);
+        assert_eq!(eui64.bits().next(), Some(true));
+        assert_eq!(eui64.bits().next_back(), Some(true));
+        assert_eq!(eui64.bits().filter(|&bit| bit).count(), 2);
+
+        let eui48 = Eui48::from(85204980412143);
+        let value = eui48
+            .bits()
+            .fold(0u64, |value, bit| value << 1 | bit as u64);
+
+        assert_eq!(value, 85204980412143);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_get_bit_out_of_range() {
+        Eui48::from(0).get_bit(48);
+    }
+}
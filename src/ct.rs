@@ -0,0 +1,56 @@
+use crate::Eui;
+
+/// Compares all octets without exiting early. `black_box` only discourages
+/// the optimizer from turning this back into an early exit, it guarantees
+/// nothing.
+#[inline(never)]
+fn ct_eq_bytes(a: &[u8], b: &[u8]) -> bool {
+    let difference = a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y));
+
+    core::hint::black_box(difference) == 0
+}
+
+impl<const N: usize> Eui<N> {
+    /// Same as `==`, but without exiting at the first differing octet.
+    ///
+    /// This is best-effort only, as the compiler may still branch on the
+    /// result. Code comparing identifiers derived from secrets should use the
+    /// `subtle::ConstantTimeEq` impl of the `subtle` feature instead.
+    pub fn eq_constant_time(&self, other: &Self) -> bool {
+        ct_eq_bytes(&self.0, &other.0)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<const N: usize> subtle::ConstantTimeEq for Eui<N> {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.0[..].ct_eq(&other.0[..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui64};
+
+    #[test]
+    fn test_eq_constant_time() {
+        let eui48 = Eui48::from(85204980412143);
+        let eui64 = Eui64::from(5583992946972634863);
+
+        assert!(eui48.eq_constant_time(&Eui48::from(85204980412143)));
+        assert!(!eui48.eq_constant_time(&Eui48::from(85204980412142)));
+        assert!(eui64.eq_constant_time(&Eui64::from(eui48)));
+        assert!(!eui64.eq_constant_time(&Eui64::BROADCAST));
+    }
+
+    #[cfg(feature = "subtle")]
+    #[test]
+    fn test_subtle_ct_eq() {
+        use subtle::ConstantTimeEq;
+
+        let eui48 = Eui48::from(85204980412143);
+
+        assert!(bool::from(eui48.ct_eq(&eui48)));
+        assert!(!bool::from(eui48.ct_eq(&Eui48::BROADCAST)));
+    }
+}
@@ -1,20 +1,97 @@
-use crate::{string_to_eui, Eui48, Eui64, StringToEuiError};
+use crate::registry::DeviceRegistry;
+use crate::{string_to_eui, Eui, Eui60, LinkLayerAddr, Oui, ParseEuiError};
+use core::convert::TryFrom;
 use core::fmt;
-use serde::de::Visitor;
-use serde::de::{Error, Unexpected};
+use core::marker::PhantomData;
+use serde::de::{Error, Expected, MapAccess, SeqAccess, Unexpected, Visitor};
 use serde::{Deserialize, Deserializer};
 
-struct Eui48Visitor;
-struct Eui64Visitor;
+struct EuiVisitor<const N: usize>;
+struct Eui60Visitor;
+struct OuiVisitor;
+struct LinkLayerAddrVisitor;
+struct DeviceRegistryVisitor<K, V, const N: usize>(PhantomData<(K, V)>);
+
+fn parse_error<E: Error>(error: ParseEuiError, expected: &dyn Expected) -> E {
+    match error {
+        ParseEuiError::InvalidLength { length } => Error::invalid_length(length, expected),
+        ParseEuiError::InvalidChar { char, .. } => {
+            Error::invalid_value(Unexpected::Char(char), expected)
+        }
+        ParseEuiError::InvalidSeparatorPlace { .. } => {
+            Error::custom("Separator must be placed after every second character")
+        }
+        ParseEuiError::OnlyOneSeparatorTypeExpected { .. } => {
+            Error::custom("Only one type of separator should be used")
+        }
+        ParseEuiError::TooManyAddresses { .. } => Error::custom("Too many addresses"),
+    }
+}
+
+/// Fills `result` from a sequence of exactly `result.len()` octets.
+fn seq_to_bytes<'de, A: SeqAccess<'de>>(
+    mut seq: A,
+    result: &mut [u8],
+    expected: &dyn Expected,
+) -> Result<(), A::Error> {
+    for (i, byte) in result.iter_mut().enumerate() {
+        *byte = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(i, expected))?;
+    }
+
+    if seq.next_element::<u8>()?.is_some() {
+        return Err(Error::invalid_length(result.len() + 1, expected));
+    }
+
+    Ok(())
+}
+
+/// Expected input of a visitor in terms of the address length.
+enum EuiExpected {
+    Bytes(usize),
+    Integer(usize),
+}
+
+impl Expected for EuiExpected {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EuiExpected::Bytes(length) => write!(formatter, "{} bytes", length),
+            EuiExpected::Integer(length) => {
+                write!(formatter, "{}-bit unsigned integer", length * 8)
+            }
+        }
+    }
+}
+
+impl<const N: usize> EuiVisitor<N> {
+    /// Unseparated, Cisco dotted and separated after every octet.
+    fn is_string_length(length: usize) -> bool {
+        length == 2 * N || length == 2 * N + N / 2 - 1 || length == 3 * N - 1
+    }
+
+    fn visit_ascii<E: Error>(self, v: &[u8]) -> Result<Eui<N>, E> {
+        let mut result = [0; N];
+
+        match string_to_eui(v, &mut result[..]) {
+            Err(error) => Err(parse_error(error, &self)),
+            Ok(()) => Ok(Eui(result)),
+        }
+    }
+}
 
-impl<'de> Visitor<'de> for Eui48Visitor {
-    type Value = Eui48;
+impl<'de, const N: usize> Visitor<'de> for EuiVisitor<N> {
+    type Value = Eui<N>;
 
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         write!(
             formatter,
-            "12 byte string with only hexadecimal characters or \
-             17 byte string with hexadecimal characters and separator after every second character"
+            "{} byte string with only hexadecimal characters, \
+             {} byte string with dot after every fourth character or \
+             {} byte string with hexadecimal characters and separator after every second character",
+            2 * N,
+            2 * N + N / 2 - 1,
+            3 * N - 1
         )
     }
 
@@ -22,40 +99,79 @@ impl<'de> Visitor<'de> for Eui48Visitor {
     where
         E: Error,
     {
-        if v.len() != 12 && v.len() != 17 {
+        if !Self::is_string_length(v.len()) {
             return Err(Error::invalid_length(v.len(), &self));
         }
 
-        let mut result = [0; 6];
+        self.visit_ascii(v.as_bytes())
+    }
 
-        match string_to_eui(v, &mut result[..]) {
-            Err(StringToEuiError::InvalidLength { length }) => {
-                return Err(Error::invalid_length(length, &self));
-            }
-            Err(StringToEuiError::InvalidChar { char }) => {
-                return Err(Error::invalid_value(Unexpected::Char(char), &self));
-            }
-            Err(StringToEuiError::InvalidSeparatorPlace) => {
-                return Err(Error::custom(
-                    "Separator must be placed after every second character",
-                ))
-            }
-            Err(StringToEuiError::OnlyOneSeparatorTypeExpected) => {
-                return Err(Error::custom("Only one type of separator should be used"));
-            }
-            Ok(()) => return Ok(Eui48(result)),
+    /// Strings written without `serde-bytes`, e.g. by postcard or bincode,
+    /// arrive here too, as those encode both alike.
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        if v.len() != N && Self::is_string_length(v.len()) {
+            return self.visit_ascii(v);
+        }
+
+        <[u8; N]>::try_from(v)
+            .map(Eui)
+            .map_err(|_| Error::invalid_length(v.len(), &EuiExpected::Bytes(N)))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        let bytes = v.to_be_bytes();
+
+        if N > bytes.len() || bytes[..bytes.len() - N].iter().any(|&byte| byte != 0) {
+            return Err(Error::invalid_value(
+                Unexpected::Unsigned(v),
+                &EuiExpected::Integer(N),
+            ));
+        }
+
+        let mut result = [0; N];
+        result.copy_from_slice(&bytes[bytes.len() - N..]);
+
+        Ok(Eui(result))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        if v < 0 {
+            return Err(Error::invalid_value(
+                Unexpected::Signed(v),
+                &EuiExpected::Integer(N),
+            ));
         }
+
+        self.visit_u64(v as u64)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut result = [0; N];
+        seq_to_bytes(seq, &mut result, &EuiExpected::Bytes(N))?;
+
+        Ok(Eui(result))
     }
 }
 
-impl<'de> Visitor<'de> for Eui64Visitor {
-    type Value = Eui64;
+impl<'de> Visitor<'de> for Eui60Visitor {
+    type Value = Eui60;
 
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         write!(
             formatter,
-            "16 byte string with only hexadecimal characters or \
-             23 byte string with hexadecimal characters and separator after every second character"
+            "15 byte string with only hexadecimal characters or 60-bit unsigned integer"
         )
     }
 
@@ -63,59 +179,215 @@ impl<'de> Visitor<'de> for Eui64Visitor {
     where
         E: Error,
     {
-        if v.len() != 16 && v.len() != 23 {
+        Eui60::parse_str(v).map_err(|error| parse_error(error, &self))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        if v > 0x0fff_ffff_ffff_ffff {
+            return Err(Error::invalid_value(Unexpected::Unsigned(v), &self));
+        }
+
+        Ok(Eui60(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        if v < 0 {
+            return Err(Error::invalid_value(Unexpected::Signed(v), &self));
+        }
+
+        self.visit_u64(v as u64)
+    }
+}
+
+impl<'de> Visitor<'de> for OuiVisitor {
+    type Value = Oui;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "6 byte string with only hexadecimal characters or \
+             8 byte string with hexadecimal characters and separator after every second character"
+        )
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        if v.len() != 6 && v.len() != 8 {
             return Err(Error::invalid_length(v.len(), &self));
         }
 
-        let mut result = [0; 8];
+        self.visit_bytes(v.as_bytes())
+    }
 
-        match string_to_eui(v, &mut result[..]) {
-            Err(StringToEuiError::InvalidLength { length }) => {
-                return Err(Error::invalid_length(length, &self));
-            }
-            Err(StringToEuiError::InvalidChar { char }) => {
-                return Err(Error::invalid_value(Unexpected::Char(char), &self));
-            }
-            Err(StringToEuiError::InvalidSeparatorPlace) => {
-                return Err(Error::custom(
-                    "Separator must be placed after every second character",
-                ))
-            }
-            Err(StringToEuiError::OnlyOneSeparatorTypeExpected) => {
-                return Err(Error::custom("Only one type of separator should be used"));
-            }
-            Ok(()) => return Ok(Eui64(result)),
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        // Strings of compact formats not telling the two apart, see `Eui<N>`.
+        if v.len() == 6 || v.len() == 8 {
+            let mut result = [0; 3];
+
+            return match string_to_eui(v, &mut result[..]) {
+                Err(error) => Err(parse_error(error, &self)),
+                Ok(()) => Ok(Oui(result)),
+            };
         }
+
+        <[u8; 3]>::try_from(v)
+            .map(Oui)
+            .map_err(|_| Error::invalid_length(v.len(), &"3 bytes"))
     }
 }
 
-impl<'de> Deserialize<'de> for Eui48 {
+impl<'de> Visitor<'de> for LinkLayerAddrVisitor {
+    type Value = LinkLayerAddr;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "EUI-48 or EUI-64 as hexadecimal string or 6 or 8 bytes"
+        )
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match v.len() {
+            12 | 14 | 17 => EuiVisitor::<6>.visit_str(v).map(LinkLayerAddr::Eui48),
+            16 | 19 | 23 => EuiVisitor::<8>.visit_str(v).map(LinkLayerAddr::Eui64),
+            length => Err(Error::invalid_length(length, &self)),
+        }
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match v.len() {
+            6 | 12 | 14 | 17 => EuiVisitor::<6>.visit_bytes(v).map(LinkLayerAddr::Eui48),
+            8 | 16 | 19 | 23 => EuiVisitor::<8>.visit_bytes(v).map(LinkLayerAddr::Eui64),
+            length => Err(Error::invalid_length(length, &self)),
+        }
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for Eui<N> {
     fn deserialize<D>(deserializer: D) -> Result<Self, <D>::Error>
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_str(Eui48Visitor)
+        let () = Eui::<N>::HOLDS_OUI;
+
+        // Integers and sequences can only be told apart from strings in
+        // self-describing formats.
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(EuiVisitor)
+        } else {
+            deserializer.deserialize_bytes(EuiVisitor)
+        }
     }
 }
 
-impl<'de> Deserialize<'de> for Eui64 {
+impl<'de> Deserialize<'de> for Eui60 {
     fn deserialize<D>(deserializer: D) -> Result<Self, <D>::Error>
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_str(Eui64Visitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(Eui60Visitor)
+        } else {
+            deserializer.deserialize_u64(Eui60Visitor)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Oui {
+    fn deserialize<D>(deserializer: D) -> Result<Self, <D>::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(OuiVisitor)
+        } else {
+            deserializer.deserialize_bytes(OuiVisitor)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for LinkLayerAddr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, <D>::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(LinkLayerAddrVisitor)
+        } else {
+            deserializer.deserialize_bytes(LinkLayerAddrVisitor)
+        }
+    }
+}
+
+impl<'de, K, V, const N: usize> Visitor<'de> for DeviceRegistryVisitor<K, V, N>
+where
+    K: Deserialize<'de> + PartialEq,
+    V: Deserialize<'de>,
+{
+    type Value = DeviceRegistry<K, V, N>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "map with at most {} entries", N)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut registry = DeviceRegistry::new();
+
+        while let Some((key, value)) = map.next_entry()? {
+            if registry.insert(key, value).is_err() {
+                return Err(Error::invalid_length(registry.len() + 1, &self));
+            }
+        }
+
+        Ok(registry)
+    }
+}
+
+impl<'de, K, V, const N: usize> Deserialize<'de> for DeviceRegistry<K, V, N>
+where
+    K: Deserialize<'de> + PartialEq,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, <D>::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(DeviceRegistryVisitor(PhantomData))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Eui48, Eui64};
-    use serde_test::{assert_de_tokens, assert_de_tokens_error, Token};
+    use crate::registry::DeviceRegistry;
+    use crate::{Eui48, Eui60, Eui64, LinkLayerAddr, Oui};
+    use serde_test::{
+        assert_de_tokens, assert_de_tokens_error, Compact, Configure, Readable, Token,
+    };
 
     #[test]
     fn test_eui48_deserialize_lowercase() {
         assert_de_tokens(
-            &Eui48::from(85204980412143),
+            &Eui48::from(85204980412143).readable(),
             &[Token::String("4d7e54972eef")],
         );
     }
@@ -123,7 +395,7 @@ mod tests {
     #[test]
     fn test_eui48_deserialize_uppercase() {
         assert_de_tokens(
-            &Eui48::from(85204980412143),
+            &Eui48::from(85204980412143).readable(),
             &[Token::String("4D7E54972EEF")],
         );
     }
@@ -131,7 +403,7 @@ mod tests {
     #[test]
     fn test_eui64_deserialize_lowercase() {
         assert_de_tokens(
-            &Eui64::from(5583992946972634863),
+            &Eui64::from(5583992946972634863).readable(),
             &[Token::String("4d7e540000972eef")],
         );
     }
@@ -139,61 +411,68 @@ mod tests {
     #[test]
     fn test_eui64_deserialize_uppercase() {
         assert_de_tokens(
-            &Eui64::from(5583992946972634863),
+            &Eui64::from(5583992946972634863).readable(),
             &[Token::String("4D7E540000972EEF")],
         );
     }
 
     #[test]
     fn test_eui48_deserialize_invalid_length() {
-        assert_de_tokens_error::<Eui48>(
+        assert_de_tokens_error::<Readable<Eui48>>(
             &[Token::Str("4d7e54972e")],
-            "invalid length 10, expected 12 byte string with only hexadecimal characters or \
+            "invalid length 10, expected 12 byte string with only hexadecimal characters, \
+             14 byte string with dot after every fourth character or \
              17 byte string with hexadecimal characters and separator after every second character",
         );
 
-        assert_de_tokens_error::<Eui48>(
+        assert_de_tokens_error::<Readable<Eui48>>(
             &[Token::Str("4d7e54972eefef4d")],
-            "invalid length 16, expected 12 byte string with only hexadecimal characters or \
+            "invalid length 16, expected 12 byte string with only hexadecimal characters, \
+             14 byte string with dot after every fourth character or \
              17 byte string with hexadecimal characters and separator after every second character",
         );
 
-        assert_de_tokens_error::<Eui48>(
+        assert_de_tokens_error::<Readable<Eui48>>(
             &[Token::Str("4d7e54972eefef4da")],
-            "invalid length 17, expected 12 byte string with only hexadecimal characters or \
+            "invalid length 17, expected 12 byte string with only hexadecimal characters, \
+             14 byte string with dot after every fourth character or \
              17 byte string with hexadecimal characters and separator after every second character",
         );
     }
 
     #[test]
     fn test_eui64_deserialize_invalid_length() {
-        assert_de_tokens_error::<Eui64>(
+        assert_de_tokens_error::<Readable<Eui64>>(
             &[Token::Str("4d7e54972eaa")],
-            "invalid length 12, expected 16 byte string with only hexadecimal characters or \
+            "invalid length 12, expected 16 byte string with only hexadecimal characters, \
+             19 byte string with dot after every fourth character or \
              23 byte string with hexadecimal characters and separator after every second character",
         );
 
-        assert_de_tokens_error::<Eui64>(
+        assert_de_tokens_error::<Readable<Eui64>>(
             &[Token::Str("4d7e54972eefef4ddd")],
-            "invalid length 18, expected 16 byte string with only hexadecimal characters or \
+            "invalid length 18, expected 16 byte string with only hexadecimal characters, \
+             19 byte string with dot after every fourth character or \
              23 byte string with hexadecimal characters and separator after every second character",
         );
     }
 
     #[test]
     fn test_eui48_deserialize_invalid_character() {
-        assert_de_tokens_error::<Eui48>(
+        assert_de_tokens_error::<Readable<Eui48>>(
             &[Token::Str("ad7e54972esa")],
-            "invalid value: character `s`, expected 12 byte string with only hexadecimal characters or \
+            "invalid value: character `s`, expected 12 byte string with only hexadecimal characters, \
+            14 byte string with dot after every fourth character or \
             17 byte string with hexadecimal characters and separator after every second character",
         );
     }
 
     #[test]
     fn test_eui64_deserialize_invalid_character() {
-        assert_de_tokens_error::<Eui64>(
+        assert_de_tokens_error::<Readable<Eui64>>(
             &[Token::Str("ad7e54972ea721sa")],
-            "invalid value: character `s`, expected 16 byte string with only hexadecimal characters or \
+            "invalid value: character `s`, expected 16 byte string with only hexadecimal characters, \
+             19 byte string with dot after every fourth character or \
              23 byte string with hexadecimal characters and separator after every second character",
         );
     }
@@ -201,12 +480,12 @@ mod tests {
     #[test]
     fn test_eui48_deserialize_with_separator_lowercase() {
         assert_de_tokens(
-            &Eui48::from(85204980412143),
+            &Eui48::from(85204980412143).readable(),
             &[Token::String("4d:7e:54:97:2e:ef")],
         );
 
         assert_de_tokens(
-            &Eui48::from(85204980412143),
+            &Eui48::from(85204980412143).readable(),
             &[Token::String("4d-7e-54-97-2e-ef")],
         );
     }
@@ -214,12 +493,12 @@ mod tests {
     #[test]
     fn test_eui48_deserialize_with_separator_uppercase() {
         assert_de_tokens(
-            &Eui48::from(85204980412143),
+            &Eui48::from(85204980412143).readable(),
             &[Token::String("4D:7E:54:97:2E:EF")],
         );
 
         assert_de_tokens(
-            &Eui48::from(85204980412143),
+            &Eui48::from(85204980412143).readable(),
             &[Token::String("4D-7E-54-97-2E-EF")],
         );
     }
@@ -227,12 +506,12 @@ mod tests {
     #[test]
     fn test_eui64_deserialize_with_separator_lowercase() {
         assert_de_tokens(
-            &Eui64::from(5583992946972634863),
+            &Eui64::from(5583992946972634863).readable(),
             &[Token::String("4d:7e:54:00:00:97:2e:ef")],
         );
 
         assert_de_tokens(
-            &Eui64::from(5583992946972634863),
+            &Eui64::from(5583992946972634863).readable(),
             &[Token::String("4d-7e-54-00-00-97-2e-ef")],
         );
     }
@@ -240,29 +519,42 @@ mod tests {
     #[test]
     fn test_eui64_deserialize_with_separator_uppercase() {
         assert_de_tokens(
-            &Eui64::from(5583992946972634863),
+            &Eui64::from(5583992946972634863).readable(),
             &[Token::String("4D:7E:54:00:00:97:2E:EF")],
         );
 
         assert_de_tokens(
-            &Eui64::from(5583992946972634863),
+            &Eui64::from(5583992946972634863).readable(),
             &[Token::String("4D-7E-54-00-00-97-2E-EF")],
         );
     }
 
+    #[test]
+    fn test_eui48_deserialize_cisco_and_spaces() {
+        assert_de_tokens(
+            &Eui48::from(85204980412143).readable(),
+            &[Token::Str("4d7e.5497.2eef")],
+        );
+
+        assert_de_tokens(
+            &Eui48::from(85204980412143).readable(),
+            &[Token::Str("4d 7e 54 97 2e ef")],
+        );
+    }
+
     #[test]
     fn test_eui48_deserialize_invalid_separator_position() {
-        assert_de_tokens_error::<Eui48>(
+        assert_de_tokens_error::<Readable<Eui48>>(
             &[Token::Str(":4d7e:54:97:2e:ef")],
             "Separator must be placed after every second character",
         );
 
-        assert_de_tokens_error::<Eui48>(
+        assert_de_tokens_error::<Readable<Eui48>>(
             &[Token::Str("4d:7e:54:97:2eef:")],
             "Separator must be placed after every second character",
         );
 
-        assert_de_tokens_error::<Eui48>(
+        assert_de_tokens_error::<Readable<Eui48>>(
             &[Token::Str("4d::7e54:97:2e:ef")],
             "Separator must be placed after every second character",
         );
@@ -270,17 +562,17 @@ mod tests {
 
     #[test]
     fn test_eui64_deserialize_invalid_separator_position() {
-        assert_de_tokens_error::<Eui64>(
+        assert_de_tokens_error::<Readable<Eui64>>(
             &[Token::Str(":4d7e:54:00:00:97:2e:ef")],
             "Separator must be placed after every second character",
         );
 
-        assert_de_tokens_error::<Eui64>(
+        assert_de_tokens_error::<Readable<Eui64>>(
             &[Token::Str("4d:7e:54:00:00:97:2eef:")],
             "Separator must be placed after every second character",
         );
 
-        assert_de_tokens_error::<Eui64>(
+        assert_de_tokens_error::<Readable<Eui64>>(
             &[Token::Str("4d::7e54:00:00:97:2e:ef")],
             "Separator must be placed after every second character",
         );
@@ -288,7 +580,7 @@ mod tests {
 
     #[test]
     fn test_eui48_deserialize_different_separators() {
-        assert_de_tokens_error::<Eui48>(
+        assert_de_tokens_error::<Readable<Eui48>>(
             &[Token::Str("4d:7e:54-97:2e:ef")],
             "Only one type of separator should be used",
         );
@@ -296,9 +588,260 @@ mod tests {
 
     #[test]
     fn test_eui64_deserialize_different_separators() {
-        assert_de_tokens_error::<Eui64>(
+        assert_de_tokens_error::<Readable<Eui64>>(
             &[Token::Str("4d:7e-54:00:00:97:2e-ef")],
             "Only one type of separator should be used",
         );
     }
+
+    #[test]
+    fn test_eui48_deserialize_compact() {
+        assert_de_tokens(
+            &Eui48::from(85204980412143).compact(),
+            &[Token::Bytes(&[0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef])],
+        );
+
+        assert_de_tokens(
+            &Eui48::from(85204980412143).compact(),
+            &[Token::Str("4d:7e:54:97:2e:ef")],
+        );
+    }
+
+    #[test]
+    fn test_eui48_deserialize_compact_bytes() {
+        assert_de_tokens(
+            &Eui48::from(85204980412143).compact(),
+            &[Token::Bytes(b"4d:7e:54:97:2e:ef")],
+        );
+        assert_de_tokens(&Oui::from(0x4D7E54).compact(), &[Token::Bytes(b"4d7e54")]);
+        assert_de_tokens(
+            &LinkLayerAddr::from(Eui64::from(5583992946972634863)).compact(),
+            &[Token::Bytes(b"4D7E540000972EEF")],
+        );
+    }
+
+    #[test]
+    fn test_postcard_round_trip() {
+        let eui48 = Eui48::from(85204980412143);
+        let mut buf = [0; 32];
+
+        let bytes = postcard::to_slice(&eui48, &mut buf).unwrap();
+        #[cfg(feature = "serde-bytes")]
+        assert_eq!(bytes, [6, 0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef]);
+        assert_eq!(postcard::from_bytes::<Eui48>(bytes), Ok(eui48));
+
+        // As written without `serde-bytes`, or by releases before it.
+        for string in ["4D-7E-54-97-2E-EF", "4d7e.5497.2eef", "4d7e54972eef"] {
+            let bytes = postcard::to_slice(string, &mut buf).unwrap();
+
+            assert_eq!(postcard::from_bytes::<Eui48>(bytes), Ok(eui48));
+            assert_eq!(
+                postcard::from_bytes::<LinkLayerAddr>(bytes),
+                Ok(LinkLayerAddr::Eui48(eui48))
+            );
+        }
+
+        let bytes = postcard::to_slice("4D-7E-54-00-00-97-2E-EF", &mut buf).unwrap();
+        assert_eq!(postcard::from_bytes::<Eui64>(bytes), Ok(Eui64::from(eui48)));
+
+        let bytes = postcard::to_slice("4D-7E-54", &mut buf).unwrap();
+        assert_eq!(postcard::from_bytes::<Oui>(bytes), Ok(eui48.oui()));
+    }
+
+    #[test]
+    fn test_eui64_deserialize_compact() {
+        assert_de_tokens(
+            &Eui64::from(5583992946972634863).compact(),
+            &[Token::Bytes(&[
+                0x4d, 0x7e, 0x54, 0x00, 0x00, 0x97, 0x2e, 0xef,
+            ])],
+        );
+    }
+
+    #[test]
+    fn test_eui48_deserialize_invalid_bytes_length() {
+        assert_de_tokens_error::<Compact<Eui48>>(
+            &[Token::Bytes(&[0x4d, 0x7e, 0x54])],
+            "invalid length 3, expected 6 bytes",
+        );
+    }
+
+    #[test]
+    fn test_eui48_deserialize_integer() {
+        assert_de_tokens(
+            &Eui48::from(85204980412143).readable(),
+            &[Token::U64(85204980412143)],
+        );
+
+        assert_de_tokens(
+            &Eui48::from(85204980412143).readable(),
+            &[Token::I64(85204980412143)],
+        );
+    }
+
+    #[test]
+    fn test_eui48_deserialize_invalid_integer() {
+        assert_de_tokens_error::<Readable<Eui48>>(
+            &[Token::U64(1 << 48)],
+            "invalid value: integer `281474976710656`, expected 48-bit unsigned integer",
+        );
+
+        assert_de_tokens_error::<Readable<Eui48>>(
+            &[Token::I64(-1)],
+            "invalid value: integer `-1`, expected 48-bit unsigned integer",
+        );
+    }
+
+    #[test]
+    fn test_eui64_deserialize_integer() {
+        assert_de_tokens(
+            &Eui64::from(5583992946972634863).readable(),
+            &[Token::U64(5583992946972634863)],
+        );
+    }
+
+    #[test]
+    fn test_eui48_deserialize_seq() {
+        assert_de_tokens(
+            &Eui48::from(85204980412143).readable(),
+            &[
+                Token::Seq { len: Some(6) },
+                Token::U8(0x4d),
+                Token::U8(0x7e),
+                Token::U8(0x54),
+                Token::U8(0x97),
+                Token::U8(0x2e),
+                Token::U8(0xef),
+                Token::SeqEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_eui64_deserialize_invalid_seq_length() {
+        assert_de_tokens_error::<Readable<Eui64>>(
+            &[
+                Token::Seq { len: Some(2) },
+                Token::U8(0x4d),
+                Token::U8(0x7e),
+                Token::SeqEnd,
+            ],
+            "invalid length 2, expected 8 bytes",
+        );
+    }
+
+    #[test]
+    fn test_eui60_deserialize() {
+        let eui60 = Eui60::from(0x4D7E54123456789);
+
+        assert_de_tokens(&eui60.readable(), &[Token::Str("4d7e54123456789")]);
+        assert_de_tokens(&eui60.readable(), &[Token::U64(0x4D7E54123456789)]);
+        assert_de_tokens(&eui60.compact(), &[Token::U64(0x4D7E54123456789)]);
+    }
+
+    #[test]
+    fn test_eui60_deserialize_invalid() {
+        assert_de_tokens_error::<Readable<Eui60>>(
+            &[Token::Str("4d7e5412345678")],
+            "invalid length 14, expected 15 byte string with only hexadecimal characters or \
+             60-bit unsigned integer",
+        );
+
+        assert_de_tokens_error::<Readable<Eui60>>(
+            &[Token::U64(1 << 60)],
+            "invalid value: integer `1152921504606846976`, expected 15 byte string with only \
+             hexadecimal characters or 60-bit unsigned integer",
+        );
+    }
+
+    #[test]
+    fn test_oui_deserialize() {
+        assert_de_tokens(&Oui::from(0x4D7E54).readable(), &[Token::Str("4d:7e:54")]);
+        assert_de_tokens(&Oui::from(0x4D7E54).readable(), &[Token::Str("4D7E54")]);
+    }
+
+    #[test]
+    fn test_oui_deserialize_invalid_length() {
+        assert_de_tokens_error::<Readable<Oui>>(
+            &[Token::Str("4d7e5")],
+            "invalid length 5, expected 6 byte string with only hexadecimal characters or \
+             8 byte string with hexadecimal characters and separator after every second character",
+        );
+    }
+
+    #[test]
+    fn test_link_layer_addr_deserialize_string() {
+        assert_de_tokens(
+            &LinkLayerAddr::from(Eui48::from(85204980412143)).readable(),
+            &[Token::Str("4d:7e:54:97:2e:ef")],
+        );
+
+        assert_de_tokens(
+            &LinkLayerAddr::from(Eui64::from(5583992946972634863)).readable(),
+            &[Token::Str("4D7E540000972EEF")],
+        );
+    }
+
+    #[test]
+    fn test_link_layer_addr_deserialize_bytes() {
+        assert_de_tokens(
+            &LinkLayerAddr::from(Eui48::from(85204980412143)).readable(),
+            &[Token::Bytes(&[0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef])],
+        );
+
+        assert_de_tokens(
+            &LinkLayerAddr::from(Eui64::from(5583992946972634863)).readable(),
+            &[Token::Bytes(&[
+                0x4d, 0x7e, 0x54, 0x00, 0x00, 0x97, 0x2e, 0xef,
+            ])],
+        );
+    }
+
+    #[test]
+    fn test_link_layer_addr_deserialize_invalid_length() {
+        assert_de_tokens_error::<Readable<LinkLayerAddr>>(
+            &[Token::Str("4d7e54")],
+            "invalid length 6, expected EUI-48 or EUI-64 as hexadecimal string or 6 or 8 bytes",
+        );
+
+        assert_de_tokens_error::<Readable<LinkLayerAddr>>(
+            &[Token::Bytes(&[0x4d, 0x7e, 0x54])],
+            "invalid length 3, expected EUI-48 or EUI-64 as hexadecimal string or 6 or 8 bytes",
+        );
+    }
+
+    #[test]
+    fn test_registry_deserialize() {
+        let mut registry = DeviceRegistry::<Eui64, u8, 2>::new();
+        registry
+            .insert(Eui64::from(5583992946972634863), 1)
+            .unwrap();
+        registry.insert(Eui64::from(1), 2).unwrap();
+
+        assert_de_tokens(
+            &registry.readable(),
+            &[
+                Token::Map { len: Some(2) },
+                Token::Str("4D-7E-54-00-00-97-2E-EF"),
+                Token::U8(1),
+                Token::Str("0000000000000001"),
+                Token::U8(2),
+                Token::MapEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_registry_deserialize_too_many_entries() {
+        assert_de_tokens_error::<Readable<DeviceRegistry<Eui48, u8, 1>>>(
+            &[
+                Token::Map { len: Some(2) },
+                Token::Str("4D-7E-54-97-2E-EF"),
+                Token::U8(1),
+                Token::Str("000000000001"),
+                Token::U8(2),
+            ],
+            "invalid length 2, expected map with at most 1 entries",
+        );
+    }
 }
@@ -0,0 +1,64 @@
+use crate::{Eui48, Eui60, Eui64, LinkLayerAddr, Oui};
+use defmt::{Format, Formatter};
+
+// Octets are sent as raw bytes and only laid out by the host, matching the
+// default `Display` output.
+
+impl Format for Eui48 {
+    fn format(&self, f: Formatter) {
+        let b = self.0;
+
+        defmt::write!(
+            f,
+            "{=u8:02X}-{=u8:02X}-{=u8:02X}-{=u8:02X}-{=u8:02X}-{=u8:02X}",
+            b[0],
+            b[1],
+            b[2],
+            b[3],
+            b[4],
+            b[5]
+        )
+    }
+}
+
+impl Format for Eui64 {
+    fn format(&self, f: Formatter) {
+        let b = self.0;
+
+        defmt::write!(
+            f,
+            "{=u8:02X}-{=u8:02X}-{=u8:02X}-{=u8:02X}-{=u8:02X}-{=u8:02X}-{=u8:02X}-{=u8:02X}",
+            b[0],
+            b[1],
+            b[2],
+            b[3],
+            b[4],
+            b[5],
+            b[6],
+            b[7]
+        )
+    }
+}
+
+impl Format for Eui60 {
+    fn format(&self, f: Formatter) {
+        defmt::write!(f, "{=u64:015X}", self.0)
+    }
+}
+
+impl Format for Oui {
+    fn format(&self, f: Formatter) {
+        let b = self.0;
+
+        defmt::write!(f, "{=u8:02X}-{=u8:02X}-{=u8:02X}", b[0], b[1], b[2])
+    }
+}
+
+impl Format for LinkLayerAddr {
+    fn format(&self, f: Formatter) {
+        match self {
+            LinkLayerAddr::Eui48(eui48) => Format::format(eui48, f),
+            LinkLayerAddr::Eui64(eui64) => Format::format(eui64, f),
+        }
+    }
+}
@@ -0,0 +1,262 @@
+use crate::format::{display_format, Case};
+use crate::{char_at, Eui64, Oui, ParseEuiError};
+use core::convert::TryFrom;
+use core::fmt::{Display, Error, Formatter, LowerHex, UpperHex, Write};
+use core::str::FromStr;
+use heapless::String;
+
+const DIGITS: usize = 15;
+
+/// 60-bit identifier of the EUI family: a 24-bit OUI followed by a 36-bit
+/// extension, as still found in FibreChannel and IEEE 1394 stacks.
+///
+/// Written as 15 hex digits without separators, e.g. `4D7E54123456789`.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+pub struct Eui60(pub(crate) u64);
+
+impl Eui60 {
+    /// Takes the lower 60 bits of `value`.
+    #[inline]
+    pub const fn new(value: u64) -> Self {
+        Eui60(value & 0x0fff_ffff_ffff_ffff)
+    }
+
+    /// Identifier from a vendor prefix and the extension. The upper 28 bits of
+    /// `extension` are ignored.
+    pub const fn from_oui_and_extension(oui: Oui, extension: u64) -> Self {
+        let [o1, o2, o3] = oui.0;
+        let oui = u32::from_be_bytes([0, o1, o2, o3]) as u64;
+
+        Eui60(oui << 36 | extension & 0xf_ffff_ffff)
+    }
+
+    pub const fn oui(&self) -> Oui {
+        let [_, _, _, _, _, o1, o2, o3] = (self.0 >> 36).to_be_bytes();
+
+        Oui([o1, o2, o3])
+    }
+
+    /// Lower 36 bits following the OUI.
+    #[inline]
+    pub const fn extension(&self) -> u64 {
+        self.0 & 0xf_ffff_ffff
+    }
+
+    /// Same as `TryFrom<&str>`, usable in constant expressions.
+    pub const fn parse_str(value: &str) -> Result<Self, ParseEuiError> {
+        let input = value.as_bytes();
+
+        if input.len() != DIGITS {
+            return Err(ParseEuiError::InvalidLength {
+                length: input.len(),
+            });
+        }
+
+        let mut result = 0;
+        let mut i = 0;
+
+        while i < input.len() {
+            let c = input[i];
+
+            let digit = match c {
+                b'A'..=b'F' => c - b'A' + 10,
+                b'a'..=b'f' => c - b'a' + 10,
+                b'0'..=b'9' => c - b'0',
+                _ => {
+                    return Err(ParseEuiError::InvalidChar {
+                        char: char_at(input, i),
+                        position: i,
+                    })
+                }
+            };
+
+            result = result << 4 | digit as u64;
+            i += 1;
+        }
+
+        Ok(Eui60(result))
+    }
+
+    #[inline]
+    pub fn to_string(&self) -> String<15> {
+        self.format(Case::Upper)
+    }
+
+    pub fn format(&self, case: Case) -> String<15> {
+        let mut string = String::new();
+
+        self.write_digits(&mut string, case)
+            .expect("String is not long enough");
+
+        string
+    }
+
+    fn write_digits<W: Write>(&self, out: &mut W, case: Case) -> Result<(), Error> {
+        let hex_chars = case.hex_chars();
+
+        for i in (0..DIGITS).rev() {
+            out.write_char(hex_chars[(self.0 >> (i * 4) & 0xf) as usize] as char)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// EUI-64 doesn't encapsulate an EUI-60: the four bits following the OUI
+/// aren't zero.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Eui64ToEui60Error;
+
+impl Display for Eui64ToEui60Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_str("EUI-64 doesn't encapsulate an EUI-60")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Eui64ToEui60Error {}
+
+impl From<u64> for Eui60 {
+    /// Takes the lower 60 bits of `value`.
+    fn from(value: u64) -> Self {
+        Eui60::new(value)
+    }
+}
+
+impl From<Eui60> for u64 {
+    fn from(eui60: Eui60) -> Self {
+        eui60.0
+    }
+}
+
+impl From<Eui60> for Eui64 {
+    /// Four zero bits inserted after the OUI, keeping it in place.
+    fn from(eui60: Eui60) -> Self {
+        Eui64::new((eui60.0 >> 36) << 40 | eui60.extension())
+    }
+}
+
+impl TryFrom<Eui64> for Eui60 {
+    type Error = Eui64ToEui60Error;
+
+    /// Inverse of `From<Eui60> for Eui64`.
+    fn try_from(eui64: Eui64) -> Result<Self, Self::Error> {
+        let value = u64::from(eui64);
+
+        if value >> 36 & 0xf != 0 {
+            return Err(Eui64ToEui60Error);
+        }
+
+        Ok(Eui60((value >> 40) << 36 | value & 0xf_ffff_ffff))
+    }
+}
+
+impl TryFrom<&str> for Eui60 {
+    type Error = ParseEuiError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Eui60::parse_str(value)
+    }
+}
+
+impl FromStr for Eui60 {
+    type Err = ParseEuiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Eui60::try_from(s)
+    }
+}
+
+impl Display for Eui60 {
+    /// Only the case of the `Display` flags of the other address types applies,
+    /// as the digits aren't grouped.
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        let (_, case) = display_format(f);
+
+        self.write_digits(f, case)
+    }
+}
+
+impl UpperHex for Eui60 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+
+        self.write_digits(f, Case::Upper)
+    }
+}
+
+impl LowerHex for Eui60 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+
+        self.write_digits(f, Case::Lower)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::{Eui60, Eui64, Eui64ToEui60Error, Oui, ParseEuiError};
+    use core::convert::TryFrom;
+    use std::format;
+
+    #[test]
+    fn test_eui60_oui_and_extension() {
+        let eui60 = Eui60::from(0x4D7E54123456789);
+
+        assert_eq!(eui60.oui(), Oui::from(0x4D7E54));
+        assert_eq!(eui60.extension(), 0x123456789);
+        assert_eq!(
+            Eui60::from_oui_and_extension(Oui::from(0x4D7E54), 0x123456789),
+            eui60
+        );
+        assert_eq!(Eui60::new(u64::MAX), Eui60::from(0xFFFFFFFFFFFFFFF));
+    }
+
+    #[test]
+    fn test_eui60_to_string() {
+        let eui60 = Eui60::from(0x04D7E5412345678);
+
+        assert_eq!(eui60.to_string(), "04D7E5412345678");
+        assert_eq!(format!("{}", eui60), "04D7E5412345678");
+        assert_eq!(format!("{:#}", eui60), "04d7e5412345678");
+        assert_eq!(format!("{:#x}", eui60), "0x04d7e5412345678");
+    }
+
+    #[test]
+    fn test_eui60_from_str() {
+        assert_eq!(
+            "4d7e54123456789".parse::<Eui60>(),
+            Ok(Eui60::from(0x4D7E54123456789))
+        );
+        assert_eq!(
+            "4d7e5412345678".parse::<Eui60>(),
+            Err(ParseEuiError::InvalidLength { length: 14 })
+        );
+        assert_eq!(
+            "4d7e5412345678x".parse::<Eui60>(),
+            Err(ParseEuiError::InvalidChar {
+                char: 'x',
+                position: 14
+            })
+        );
+    }
+
+    #[test]
+    fn test_eui60_to_eui64() {
+        let eui60 = Eui60::from(0x4D7E54123456789);
+        let eui64 = Eui64::from(eui60);
+
+        assert_eq!(eui64, Eui64::from(0x4D7E540123456789));
+        assert_eq!(Eui60::try_from(eui64), Ok(eui60));
+        assert_eq!(
+            Eui60::try_from(Eui64::from(0x4D7E541123456789)),
+            Err(Eui64ToEui60Error)
+        );
+    }
+}
@@ -0,0 +1,198 @@
+//! Detection of addresses moving between ports (MAC flapping).
+//!
+//! # Example
+//!
+//! ```rust
+//! use eui::flap::FlapDetector;
+//! use eui::Eui48;
+//!
+//! let mut detector = FlapDetector::<u8, 8>::new(100);
+//! let eui48 = Eui48::from(85204980412143);
+//!
+//! assert_eq!(detector.observe(eui48, 1, 0), None);
+//! assert!(detector.observe(eui48, 2, 10).is_some());
+//! ```
+use crate::Eui48;
+use heapless::Vec;
+
+/// Last known location of an address.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Entry<P> {
+    eui: Eui48,
+    port: P,
+    last_seen: u32,
+}
+
+impl<P: Copy> Entry<P> {
+    pub fn eui(&self) -> Eui48 {
+        self.eui
+    }
+
+    pub fn port(&self) -> P {
+        self.port
+    }
+
+    pub fn last_seen(&self) -> u32 {
+        self.last_seen
+    }
+}
+
+/// Address seen on a new port sooner than the configured threshold.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MacMove<P> {
+    pub eui: Eui48,
+    pub from: P,
+    pub to: P,
+    /// Ticks since the address was last seen on `from`.
+    pub elapsed: u32,
+}
+
+/// Fixed-capacity table tracking the port and last-seen tick of up to `N`
+/// addresses. Ticks are caller-defined and allowed to wrap around.
+pub struct FlapDetector<P, const N: usize> {
+    entries: Vec<Entry<P>, N>,
+    threshold: u32,
+}
+
+impl<P, const N: usize> FlapDetector<P, N>
+where
+    P: Copy + PartialEq,
+{
+    /// Moves happening within `threshold` ticks are reported.
+    pub fn new(threshold: u32) -> Self {
+        FlapDetector {
+            entries: Vec::new(),
+            threshold,
+        }
+    }
+
+    /// Records `eui` on `port` at tick `now` and returns the move if it was
+    /// last seen on another port less than `threshold` ticks ago.
+    ///
+    /// When the table is full the least recently seen address is evicted.
+    pub fn observe(&mut self, eui: Eui48, port: P, now: u32) -> Option<MacMove<P>> {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.eui == eui) {
+            let elapsed = now.wrapping_sub(entry.last_seen);
+            let from = entry.port;
+
+            entry.port = port;
+            entry.last_seen = now;
+
+            if from != port && elapsed < self.threshold {
+                return Some(MacMove {
+                    eui,
+                    from,
+                    to: port,
+                    elapsed,
+                });
+            }
+
+            return None;
+        }
+
+        let entry = Entry {
+            eui,
+            port,
+            last_seen: now,
+        };
+
+        if let Err(entry) = self.entries.push(entry) {
+            let oldest = self
+                .entries
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, entry)| now.wrapping_sub(entry.last_seen))
+                .map(|(i, _)| i)
+                .expect("Table with capacity, but no entries");
+
+            self.entries[oldest] = entry;
+        }
+
+        None
+    }
+
+    /// Last known location of `eui`.
+    pub fn get(&self, eui: &Eui48) -> Option<&Entry<P>> {
+        self.entries.iter().find(|entry| entry.eui == *eui)
+    }
+
+    /// Stops tracking `eui`, e.g. when its port goes down.
+    pub fn forget(&mut self, eui: &Eui48) -> Option<Entry<P>> {
+        let index = self.entries.iter().position(|entry| entry.eui == *eui)?;
+
+        Some(self.entries.swap_remove(index))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FlapDetector, MacMove};
+    use crate::Eui48;
+
+    #[test]
+    fn test_flap_detector_reports_fast_move() {
+        let mut detector = FlapDetector::<u8, 4>::new(100);
+        let eui48 = Eui48::from(85204980412143);
+
+        assert_eq!(detector.observe(eui48, 1, 0), None);
+        assert_eq!(detector.observe(eui48, 1, 50), None);
+        assert_eq!(
+            detector.observe(eui48, 2, 60),
+            Some(MacMove {
+                eui: eui48,
+                from: 1,
+                to: 2,
+                elapsed: 10
+            })
+        );
+        assert_eq!(detector.get(&eui48).unwrap().port(), 2);
+    }
+
+    #[test]
+    fn test_flap_detector_ignores_slow_move() {
+        let mut detector = FlapDetector::<u8, 4>::new(100);
+        let eui48 = Eui48::from(85204980412143);
+
+        assert_eq!(detector.observe(eui48, 1, u32::MAX - 50), None);
+        assert_eq!(detector.observe(eui48, 2, 60), None);
+        assert_eq!(detector.observe(eui48, 1, 61).unwrap().elapsed, 1);
+    }
+
+    #[test]
+    fn test_flap_detector_evicts_least_recently_seen() {
+        let mut detector = FlapDetector::<u8, 2>::new(100);
+
+        detector.observe(Eui48::from(1), 1, 10);
+        detector.observe(Eui48::from(2), 1, 5);
+        detector.observe(Eui48::from(3), 1, 20);
+
+        assert_eq!(detector.len(), 2);
+        assert!(detector.get(&Eui48::from(1)).is_some());
+        assert!(detector.get(&Eui48::from(2)).is_none());
+        assert!(detector.get(&Eui48::from(3)).is_some());
+    }
+
+    #[test]
+    fn test_flap_detector_forget() {
+        let mut detector = FlapDetector::<u8, 2>::new(100);
+        let eui48 = Eui48::from(85204980412143);
+
+        detector.observe(eui48, 1, 0);
+
+        assert_eq!(detector.forget(&eui48).unwrap().port(), 1);
+        assert!(detector.is_empty());
+        assert_eq!(detector.observe(eui48, 2, 1), None);
+    }
+}
@@ -0,0 +1,306 @@
+use crate::{BufferTooSmall, LOWERCASE_HEX_CHARS, UPPERCASE_HEX_CHARS};
+use core::fmt::{Display, Error, Formatter, LowerHex, UpperHex, Write};
+
+/// Textual layout of an address.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EuiFormat {
+    /// `4d:7e:54:97:2e:ef`, as used on Unix systems.
+    Colon,
+    /// `4D-7E-54-97-2E-EF`, as used by IEEE 802.
+    Hyphen,
+    /// `4d7e.5497.2eef`, as used by Cisco.
+    CiscoDot,
+    /// `4d7e54972eef`
+    Bare,
+}
+
+impl EuiFormat {
+    /// Case used where only a format is given: uppercase for the IEEE style,
+    /// lowercase otherwise, as with the `Display` flags.
+    pub(crate) fn default_case(self) -> Case {
+        match self {
+            EuiFormat::Hyphen => Case::Upper,
+            _ => Case::Lower,
+        }
+    }
+
+    fn group_size_and_separator(self) -> (usize, char) {
+        match self {
+            EuiFormat::Colon => (2, ':'),
+            EuiFormat::Hyphen => (2, '-'),
+            EuiFormat::CiscoDot => (4, '.'),
+            EuiFormat::Bare => (0, '-'),
+        }
+    }
+}
+
+/// Case of the hex digits `A` to `F`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Case {
+    Lower,
+    Upper,
+}
+
+impl Case {
+    pub(crate) fn hex_chars(self) -> &'static [u8] {
+        match self {
+            Case::Lower => LOWERCASE_HEX_CHARS,
+            Case::Upper => UPPERCASE_HEX_CHARS,
+        }
+    }
+}
+
+/// Format selected by the `Display` flags of the address types.
+pub(crate) fn display_format(f: &Formatter<'_>) -> (EuiFormat, Case) {
+    if f.alternate() {
+        (EuiFormat::Colon, Case::Lower)
+    } else if f.sign_plus() {
+        (EuiFormat::CiscoDot, Case::Lower)
+    } else if f.sign_minus() {
+        (EuiFormat::Hyphen, Case::Lower)
+    } else {
+        (EuiFormat::Hyphen, Case::Upper)
+    }
+}
+
+pub(crate) fn write_formatted<W: Write>(
+    out: &mut W,
+    bytes: &[u8],
+    format: EuiFormat,
+    case: Case,
+) -> Result<(), Error> {
+    let (group_size, separator) = format.group_size_and_separator();
+
+    write_grouped(out, bytes, group_size, separator, case.hex_chars())
+}
+
+pub(crate) fn write_grouped<W: Write>(
+    out: &mut W,
+    bytes: &[u8],
+    group_size: usize,
+    separator: char,
+    hex_chars: &[u8],
+) -> Result<(), Error> {
+    for i in 0..bytes.len() * 2 {
+        if group_size != 0 && i != 0 && i % group_size == 0 {
+            out.write_char(separator)?;
+        }
+
+        let nibble = if i % 2 == 0 {
+            bytes[i / 2] >> 4
+        } else {
+            bytes[i / 2] & 0xf
+        };
+
+        out.write_char(hex_chars[nibble as usize] as char)?;
+    }
+
+    Ok(())
+}
+
+/// `Write` into a fixed byte buffer, failing once it is full.
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> Result<(), Error> {
+        let end = self.len + s.len();
+
+        if end > self.buf.len() {
+            return Err(Error);
+        }
+
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+
+        Ok(())
+    }
+}
+
+/// Addresses formatted one after another with `separator` in between.
+pub(crate) fn write_list<'a, 'b>(
+    buf: &'a mut [u8],
+    addresses: impl Iterator<Item = &'b [u8]>,
+    separator: char,
+    format: EuiFormat,
+) -> Result<&'a str, BufferTooSmall> {
+    let mut writer = SliceWriter { buf, len: 0 };
+
+    for (i, bytes) in addresses.enumerate() {
+        if i != 0 {
+            writer.write_char(separator).map_err(|_| BufferTooSmall)?;
+        }
+
+        write_formatted(&mut writer, bytes, format, format.default_case())
+            .map_err(|_| BufferTooSmall)?;
+    }
+
+    let SliceWriter { buf, len } = writer;
+
+    Ok(core::str::from_utf8(&buf[..len]).expect("Hex digits and separator are UTF-8"))
+}
+
+pub(crate) fn write_hex<'a>(
+    buf: &'a mut [u8],
+    bytes: &[u8],
+    format: EuiFormat,
+) -> Result<&'a str, BufferTooSmall> {
+    let mut writer = SliceWriter { buf, len: 0 };
+
+    write_formatted(&mut writer, bytes, format, format.default_case())
+        .map_err(|_| BufferTooSmall)?;
+
+    let SliceWriter { buf, len } = writer;
+
+    Ok(core::str::from_utf8(&buf[..len]).expect("Hex digits are ASCII"))
+}
+
+/// All octets as hex digits, including leading zeros, prefixed by `0x` with
+/// the `#` flag.
+pub(crate) fn fmt_hex(f: &mut Formatter<'_>, bytes: &[u8], case: Case) -> Result<(), Error> {
+    if f.alternate() {
+        f.write_str("0x")?;
+    }
+
+    write_grouped(f, bytes, 0, '-', case.hex_chars())
+}
+
+/// All octets as binary digits, including leading zeros, prefixed by `0b`
+/// with the `#` flag.
+pub(crate) fn fmt_binary(f: &mut Formatter<'_>, bytes: &[u8]) -> Result<(), Error> {
+    if f.alternate() {
+        f.write_str("0b")?;
+    }
+
+    for byte in bytes {
+        write!(f, "{:08b}", byte)?;
+    }
+
+    Ok(())
+}
+
+/// Hex digits split into groups of `group_size` digits with `separator` in
+/// between, created by `grouped()` on the address types.
+///
+/// `{}` and `{:X}` print uppercase digits, `{:x}` lowercase ones. A group size
+/// of zero disables grouping.
+#[derive(Copy, Clone, Debug)]
+pub struct Grouped<'a> {
+    pub(crate) bytes: &'a [u8],
+    pub(crate) group_size: usize,
+    pub(crate) separator: char,
+}
+
+impl Display for Grouped<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        UpperHex::fmt(self, f)
+    }
+}
+
+impl UpperHex for Grouped<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write_grouped(
+            f,
+            self.bytes,
+            self.group_size,
+            self.separator,
+            Case::Upper.hex_chars(),
+        )
+    }
+}
+
+impl LowerHex for Grouped<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write_grouped(
+            f,
+            self.bytes,
+            self.group_size,
+            self.separator,
+            Case::Lower.hex_chars(),
+        )
+    }
+}
+
+/// Address with everything after the OUI masked as `xx`, created by
+/// `redacted()` on the address types.
+#[derive(Copy, Clone, Debug)]
+pub struct Redacted<'a> {
+    pub(crate) bytes: &'a [u8],
+}
+
+impl Display for Redacted<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write_formatted(f, &self.bytes[..3], EuiFormat::Colon, Case::Lower)?;
+
+        for _ in 3..self.bytes.len() {
+            f.write_str(":xx")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::{BufferTooSmall, Eui48, Eui64, EuiFormat};
+    use std::format;
+
+    #[test]
+    fn test_eui48_write_hex() {
+        let eui48 = Eui48::from(85204980412143);
+        let mut buf = [0; 17];
+
+        assert_eq!(
+            eui48.write_hex(&mut buf, EuiFormat::Hyphen),
+            Ok("4D-7E-54-97-2E-EF")
+        );
+        assert_eq!(
+            eui48.write_hex(&mut buf, EuiFormat::CiscoDot),
+            Ok("4d7e.5497.2eef")
+        );
+        assert_eq!(
+            eui48.write_hex(&mut buf[..16], EuiFormat::Colon),
+            Err(BufferTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_eui64_write_hex() {
+        let eui64 = Eui64::from(5583992946972634863);
+        let mut buf = [0; 32];
+
+        assert_eq!(
+            eui64.write_hex(&mut buf, EuiFormat::Colon),
+            Ok("4d:7e:54:00:00:97:2e:ef")
+        );
+        assert_eq!(
+            eui64.write_hex(&mut buf[..16], EuiFormat::Bare),
+            Ok("4d7e540000972eef")
+        );
+    }
+
+    #[test]
+    fn test_eui48_grouped() {
+        let eui48 = Eui48::from(85204980412143);
+
+        assert_eq!(format!("{:x}", eui48.grouped(4, '.')), "4d7e.5497.2eef");
+        assert_eq!(format!("{}", eui48.grouped(2, ' ')), "4D 7E 54 97 2E EF");
+        assert_eq!(format!("{:X}", eui48.grouped(6, ':')), "4D7E54:972EEF");
+        assert_eq!(format!("{}", eui48.grouped(0, ':')), "4D7E54972EEF");
+    }
+
+    #[test]
+    fn test_eui64_grouped() {
+        let eui64 = Eui64::from(5583992946972634863);
+
+        assert_eq!(
+            format!("{:x}", eui64.grouped(4, '.')),
+            "4d7e.5400.0097.2eef"
+        );
+        assert_eq!(format!("{}", eui64.grouped(5, '_')), "4D7E5_40000_972EE_F");
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{Eui, Eui60, LinkLayerAddr, Oui};
+use hash32::{Hash, Hasher};
+
+// Same input as the derived `core::hash::Hash` impls, for code still keying
+// heapless 0.7 maps by address.
+
+impl<const N: usize> Hash for Eui<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(&self.0[..], state)
+    }
+}
+
+impl Hash for Eui60 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(&self.0, state)
+    }
+}
+
+impl Hash for Oui {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(&self.0, state)
+    }
+}
+
+impl Hash for LinkLayerAddr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            LinkLayerAddr::Eui48(eui48) => {
+                Hash::hash(&0u8, state);
+                Hash::hash(eui48, state)
+            }
+            LinkLayerAddr::Eui64(eui64) => {
+                Hash::hash(&1u8, state);
+                Hash::hash(eui64, state)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui64, LinkLayerAddr};
+    use hash32::{FnvHasher, Hash, Hasher};
+
+    fn hash<T: Hash>(value: &T) -> u32 {
+        let mut hasher = FnvHasher::default();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_hash32() {
+        let eui48 = Eui48::from(85204980412143);
+
+        assert_eq!(hash(&eui48), hash(&Eui48::from(85204980412143)));
+        assert_ne!(hash(&eui48), hash(&Eui48::from(1)));
+        assert_ne!(
+            hash(&LinkLayerAddr::from(eui48)),
+            hash(&LinkLayerAddr::from(Eui64::from(eui48)))
+        );
+    }
+}
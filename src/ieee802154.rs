@@ -0,0 +1,180 @@
+//! IEEE 802.15.4 short addresses, e.g. for 6LoWPAN and Thread.
+//!
+//! Short addresses are handled as `u16` in the usual notation, e.g. `0x1234`.
+//! Frames carry them little-endian, IPv6 interface identifiers big-endian.
+//!
+//! # Example
+//!
+//! ```rust
+//! use eui::ieee802154::{iid_to_short_address, short_address_to_iid};
+//!
+//! let iid = short_address_to_iid(0x1234);
+//!
+//! assert_eq!(iid, [0x00, 0x00, 0x00, 0xff, 0xfe, 0x00, 0x12, 0x34]);
+//! assert_eq!(iid_to_short_address(iid), Some(0x1234));
+//! ```
+use crate::oui::fnv1a;
+use crate::Eui64;
+
+/// Destination of frames to every device of the PAN.
+pub const BROADCAST: u16 = 0xffff;
+
+/// Marks a device that has no short address and uses its extended one.
+pub const NO_SHORT_ADDRESS: u16 = 0xfffe;
+
+/// Moves the two reserved values into the regular range.
+const fn unreserved(short: u16) -> u16 {
+    if short >= NO_SHORT_ADDRESS {
+        short & 0x7fff
+    } else {
+        short
+    }
+}
+
+/// Interface identifier `0000:00ff:fe00:XXXX` of RFC 6282 Section 3.2.2 for a
+/// short address.
+pub const fn short_address_to_iid(short: u16) -> [u8; 8] {
+    let [s1, s2] = short.to_be_bytes();
+
+    [0x00, 0x00, 0x00, 0xff, 0xfe, 0x00, s1, s2]
+}
+
+/// Interface identifier of RFC 4944 Section 6, which puts the PAN ID in the
+/// first 16 bits with the universal/local bit cleared.
+pub const fn short_address_to_iid_in_pan(pan_id: u16, short: u16) -> [u8; 8] {
+    let [p1, p2] = pan_id.to_be_bytes();
+    let [s1, s2] = short.to_be_bytes();
+
+    [p1 & !0x02, p2, 0x00, 0xff, 0xfe, 0x00, s1, s2]
+}
+
+/// Short address of an interface identifier built by
+/// [`short_address_to_iid`], `None` for other identifiers.
+///
+/// An identifier derived from an EUI-48 with the OUI `02:00:00` and a NIC
+/// starting with `00` has the same form, so this can't be told from the
+/// identifier alone. Only use it where the link is known to use short
+/// addresses, e.g. by the 6LoWPAN header compression mode.
+pub const fn iid_to_short_address(iid: [u8; 8]) -> Option<u16> {
+    match iid {
+        [0x00, 0x00, 0x00, 0xff, 0xfe, 0x00, s1, s2] => Some(u16::from_be_bytes([s1, s2])),
+        _ => None,
+    }
+}
+
+/// Short address of an interface identifier built by
+/// [`short_address_to_iid_in_pan`] for `pan_id`, `None` for other
+/// identifiers.
+///
+/// EUI-48 derived identifiers whose OUI ends in `00` and whose NIC starts with
+/// `00` look alike, see [`iid_to_short_address`].
+pub const fn iid_in_pan_to_short_address(pan_id: u16, iid: [u8; 8]) -> Option<u16> {
+    let [p1, p2] = pan_id.to_be_bytes();
+
+    match iid {
+        [i1, i2, 0x00, 0xff, 0xfe, 0x00, s1, s2] if i1 == p1 & !0x02 && i2 == p2 => {
+            Some(u16::from_be_bytes([s1, s2]))
+        }
+        _ => None,
+    }
+}
+
+impl Eui64 {
+    /// Deterministic short address for stacks without a coordinator handing
+    /// them out: the four 16-bit words of the address xor-ed together.
+    ///
+    /// The reserved [`BROADCAST`] and [`NO_SHORT_ADDRESS`] values are never
+    /// returned. Collisions are possible, so a stack should still detect
+    /// duplicates.
+    pub const fn to_short_address(&self) -> u16 {
+        let [b0, b1, b2, b3, b4, b5, b6, b7] = self.0;
+
+        unreserved(
+            u16::from_be_bytes([b0, b1])
+                ^ u16::from_be_bytes([b2, b3])
+                ^ u16::from_be_bytes([b4, b5])
+                ^ u16::from_be_bytes([b6, b7]),
+        )
+    }
+
+    /// Short address hashed from `pan_id` and the address, so devices
+    /// colliding in one PAN are unlikely to collide in another.
+    ///
+    /// The 32-bit FNV-1a hash of the big-endian PAN ID followed by the octets
+    /// is xor-folded to 16 bits, reserved values are mapped as in
+    /// [`to_short_address`](Eui64::to_short_address).
+    pub const fn to_short_address_in_pan(&self, pan_id: u16) -> u16 {
+        let [p1, p2] = pan_id.to_be_bytes();
+        let [b0, b1, b2, b3, b4, b5, b6, b7] = self.0;
+        let hash = fnv1a(&[p1, p2, b0, b1, b2, b3, b4, b5, b6, b7]);
+
+        unreserved((hash >> 16) as u16 ^ hash as u16)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        iid_in_pan_to_short_address, iid_to_short_address, short_address_to_iid,
+        short_address_to_iid_in_pan, BROADCAST, NO_SHORT_ADDRESS,
+    };
+    use crate::Eui64;
+
+    #[test]
+    fn test_to_short_address() {
+        let eui64 = Eui64::from(5583992946972634863);
+
+        assert_eq!(eui64.to_short_address(), 0x4D7E ^ 0x5400 ^ 0x0097 ^ 0x2EEF);
+        assert_eq!(Eui64::from(0xFFFF).to_short_address(), 0x7FFF);
+        assert_eq!(Eui64::from(0xFFFE).to_short_address(), 0x7FFE);
+        assert_ne!(Eui64::from(0xFFFE).to_short_address(), NO_SHORT_ADDRESS);
+    }
+
+    #[test]
+    fn test_to_short_address_in_pan() {
+        // Collide without a PAN, as the words are only xor-ed.
+        let a = Eui64::from(0x1234);
+        let b = Eui64::from(0x1234_0000);
+
+        assert_eq!(a.to_short_address(), b.to_short_address());
+
+        for pan_id in [0x0000, 0xabcd, 0xfffe] {
+            assert_ne!(
+                a.to_short_address_in_pan(pan_id),
+                b.to_short_address_in_pan(pan_id)
+            );
+            assert_ne!(a.to_short_address_in_pan(pan_id), BROADCAST);
+        }
+
+        assert_ne!(a.to_short_address_in_pan(1), a.to_short_address_in_pan(2));
+    }
+
+    #[test]
+    fn test_short_address_to_iid_in_pan() {
+        let iid = short_address_to_iid_in_pan(0xabcd, 0x0001);
+
+        assert_eq!(iid, [0xa9, 0xcd, 0x00, 0xff, 0xfe, 0x00, 0x00, 0x01]);
+        assert_eq!(iid_in_pan_to_short_address(0xabcd, iid), Some(0x0001));
+        assert_eq!(iid_in_pan_to_short_address(0x1234, iid), None);
+        assert_eq!(iid_to_short_address(iid), None);
+        assert_eq!(
+            iid_in_pan_to_short_address(0, short_address_to_iid(0x0001)),
+            Some(0x0001)
+        );
+    }
+
+    #[test]
+    fn test_iid_to_short_address_extended() {
+        let eui64 = Eui64::from(5583992946972634863);
+
+        assert_eq!(iid_to_short_address(eui64.to_ipv6_interface_id()), None);
+
+        // OUI ending in 00 and NIC starting with 00, see the docs.
+        let eui48 = crate::Eui48::from(0x4f7e00001234);
+
+        assert_eq!(
+            iid_in_pan_to_short_address(0x4d7e, eui48.to_modified_eui64().into()),
+            Some(0x1234)
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{Eui, ParseEuiError};
+
+fn is_separator(c: char) -> bool {
+    matches!(c, ':' | '-' | '.' | ' ')
+}
+
+/// Hex digits of `group` as a number, groups longer than `width` digits are
+/// reported as a missing separator.
+fn parse_group(group: &str, offset: usize, width: usize) -> Result<u64, ParseEuiError> {
+    let mut value = 0;
+
+    for (j, (i, c)) in group.char_indices().enumerate() {
+        if j == width {
+            return Err(ParseEuiError::InvalidSeparatorPlace {
+                position: offset + i,
+            });
+        }
+
+        let digit = c.to_digit(16).ok_or(ParseEuiError::InvalidChar {
+            char: c,
+            position: offset + i,
+        })?;
+
+        value = value << 4 | digit as u64;
+    }
+
+    Ok(value)
+}
+
+fn lenient_to_eui(input: &str, result: &mut [u8]) -> Result<(), ParseEuiError> {
+    let trimmed = input.trim_start();
+    let mut offset = input.len() - trimmed.len();
+    let mut value = trimmed.trim_end();
+
+    let prefixed = value.starts_with("0x") || value.starts_with("0X");
+
+    if prefixed {
+        value = &value[2..];
+        offset += 2;
+    }
+
+    if !value.contains(is_separator) {
+        // A prefixed value is a number, so leading zeros may be left out.
+        let digits = value.chars().count();
+
+        if digits == 0 || digits > result.len() * 2 || !prefixed && digits != result.len() * 2 {
+            return Err(ParseEuiError::InvalidLength { length: digits });
+        }
+
+        // Reports the first invalid character, so only hex digits are left.
+        parse_group(value, offset, digits)?;
+
+        for (i, byte) in result.iter_mut().rev().enumerate() {
+            let end = value.len().saturating_sub(2 * i);
+
+            *byte = parse_group(&value[end.saturating_sub(2)..end], offset, 2)? as u8;
+        }
+
+        return Ok(());
+    }
+
+    // Either one group per octet or, as done by Cisco, one per two octets.
+    let groups = value.split(is_separator).count();
+
+    let width = if groups == result.len() {
+        2
+    } else if groups * 2 == result.len() {
+        4
+    } else {
+        return Err(ParseEuiError::InvalidLength {
+            length: value.len(),
+        });
+    };
+
+    for (chunk, group) in result.chunks_mut(width / 2).zip(value.split(is_separator)) {
+        if group.is_empty() {
+            return Err(ParseEuiError::InvalidSeparatorPlace { position: offset });
+        }
+
+        let bytes = parse_group(group, offset, width)?.to_be_bytes();
+        chunk.copy_from_slice(&bytes[bytes.len() - chunk.len()..]);
+
+        offset += group.len() + 1;
+    }
+
+    Ok(())
+}
+
+impl<const N: usize> Eui<N> {
+    /// Forgiving counterpart of `TryFrom<&str>` for addresses typed by hand or
+    /// scraped from logs.
+    ///
+    /// Mixed separators, octets without leading zero like in
+    /// `4d:7:54:97:2e:ef`, surrounding whitespace and a `0x` prefix are
+    /// accepted as well. With the prefix and no separators the value is read
+    /// as a number, so leading zeros may be left out.
+    pub fn parse_lenient(value: &str) -> Result<Self, ParseEuiError> {
+        let mut result = [0; N];
+
+        lenient_to_eui(value, &mut result)?;
+
+        Ok(Self::from_bytes(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui64, ParseEuiError};
+
+    #[test]
+    fn test_eui48_parse_lenient() {
+        let eui48 = Eui48::from(85204980412143);
+
+        assert_eq!(Eui48::parse_lenient("4d:7e:54:97:2e:ef"), Ok(eui48));
+        assert_eq!(Eui48::parse_lenient(" 4D-7e:54.97 2e:EF\n"), Ok(eui48));
+        assert_eq!(Eui48::parse_lenient("0x4d7e54972eef"), Ok(eui48));
+        assert_eq!(Eui48::parse_lenient("4d7e.5497.2eef"), Ok(eui48));
+        assert_eq!(
+            Eui48::parse_lenient("4d:7:54:97:2e:ef"),
+            Ok(Eui48::from(0x4D0754972EEF))
+        );
+        assert_eq!(Eui48::parse_lenient("0x1"), Ok(Eui48::from(1)));
+        assert_eq!(Eui48::parse_lenient("0:0:0:0:0:1"), Ok(Eui48::from(1)));
+    }
+
+    #[test]
+    fn test_eui48_parse_lenient_errors() {
+        assert_eq!(
+            Eui48::parse_lenient("4d7e54972ee"),
+            Err(ParseEuiError::InvalidLength { length: 11 })
+        );
+        assert_eq!(
+            Eui48::parse_lenient(" 4d:7e:54:97:2e"),
+            Err(ParseEuiError::InvalidLength { length: 14 })
+        );
+        assert_eq!(
+            Eui48::parse_lenient("4d::54:97:2e:ef"),
+            Err(ParseEuiError::InvalidSeparatorPlace { position: 3 })
+        );
+        assert_eq!(
+            Eui48::parse_lenient("4d:7e5:4:97:2e:ef"),
+            Err(ParseEuiError::InvalidSeparatorPlace { position: 5 })
+        );
+        assert_eq!(
+            Eui48::parse_lenient("0x4d:7e:54:97:2e:eg"),
+            Err(ParseEuiError::InvalidChar {
+                char: 'g',
+                position: 18
+            })
+        );
+        assert_eq!(
+            Eui48::parse_lenient("0x"),
+            Err(ParseEuiError::InvalidLength { length: 0 })
+        );
+    }
+
+    #[test]
+    fn test_eui64_parse_lenient() {
+        let eui64 = Eui64::from(5583992946972634863);
+
+        assert_eq!(Eui64::parse_lenient("4d:7e:54:0:0:97:2e:ef"), Ok(eui64));
+        assert_eq!(Eui64::parse_lenient("4d7e.5400.97.2eef"), Ok(eui64));
+        assert_eq!(Eui64::parse_lenient("0X4D7E540000972EEF"), Ok(eui64));
+    }
+}
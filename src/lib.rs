@@ -14,502 +14,2417 @@
 //! ```
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 #[cfg(feature = "serde")]
 mod de;
 #[cfg(feature = "serde")]
 mod ser;
 
+mod bits;
+mod ct;
+#[cfg(feature = "defmt")]
+mod defmt;
+mod eui60;
+mod format;
+#[cfg(feature = "hash32")]
+mod hash32;
+mod lenient;
+mod list;
+mod mask;
+mod multicast;
+mod ops;
+mod oui;
+#[cfg(test)]
+mod proptests;
+mod range;
+#[cfg(feature = "smoltcp")]
+mod smoltcp;
+#[cfg(feature = "alloc")]
+mod string;
+#[cfg(feature = "ufmt")]
+mod ufmt;
+
+pub mod flap;
+pub mod ieee802154;
+pub mod registry;
+pub mod rng;
+pub mod scan;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "embedded-storage")]
+pub mod storage;
+pub mod vendor;
+pub mod wol;
+
+use core::array::TryFromSliceError;
+use core::cmp::Ordering;
 use core::convert::TryFrom;
-use core::fmt::{Display, Error, Formatter, LowerHex, UpperHex};
-use heapless::consts::*;
-use heapless::{String, Vec};
+use core::fmt::{Binary, Debug, Display, Error, Formatter, LowerHex, UpperHex};
+use core::str::FromStr;
+use heapless::String;
+
+pub use bits::Bits;
+pub use eui60::{Eui60, Eui64ToEui60Error};
+pub use format::{Case, EuiFormat, Grouped, Redacted};
+pub use mask::{Eui48Mask, Eui48Pattern, ParsePatternError};
+pub use oui::{Oui, RegistryKind};
+pub use range::EuiRange;
 
 const UPPERCASE_HEX_CHARS: &[u8] = b"0123456789ABCDEF";
+const LOWERCASE_HEX_CHARS: &[u8] = b"0123456789abcdef";
+
+/// Parses an [`Eui48`] at compile time, accepting the same input as
+/// `TryFrom<&str>`. Invalid input fails the build.
+///
+/// ```rust
+/// use eui::{eui48, Eui48};
+///
+/// static ALLOWED: [Eui48; 2] = [eui48!("4d:7e:54:97:2e:ef"), eui48!("4D-7E-54-97-2E-F0")];
+///
+/// assert_eq!(ALLOWED[0], Eui48::from(85204980412143));
+/// ```
+///
+/// ```compile_fail
+/// let truncated = eui::eui48!("4d:7e:54:97:2e");
+/// ```
+#[macro_export]
+macro_rules! eui48 {
+    ($value:expr) => {{
+        const EUI48: $crate::Eui48 = match $crate::Eui48::parse_str($value) {
+            ::core::result::Result::Ok(eui48) => eui48,
+            ::core::result::Result::Err(_) => panic!("Invalid EUI-48"),
+        };
 
-#[derive(Eq, PartialEq, Copy, Clone, Debug, Hash, hash32_derive::Hash32)]
-pub struct Eui48([u8; 6]);
-#[derive(Eq, PartialEq, Copy, Clone, Debug, Hash, hash32_derive::Hash32)]
-pub struct Eui64([u8; 8]);
+        EUI48
+    }};
+}
 
-macro_rules! to_hex_string {
-    ($eui: expr, $size: ty) => {{
-        let mut vec = Vec::<u8, $size>::new();
+/// Parses an [`Eui64`] at compile time, accepting the same input as
+/// `TryFrom<&str>`. Invalid input fails the build.
+///
+/// ```rust
+/// use eui::{eui64, Eui64};
+///
+/// const GATEWAY: Eui64 = eui64!("4d:7e:54:00:00:97:2e:ef");
+///
+/// assert_eq!(GATEWAY, Eui64::from(5583992946972634863));
+/// ```
+#[macro_export]
+macro_rules! eui64 {
+    ($value:expr) => {{
+        const EUI64: $crate::Eui64 = match $crate::Eui64::parse_str($value) {
+            ::core::result::Result::Ok(eui64) => eui64,
+            ::core::result::Result::Err(_) => panic!("Invalid EUI-64"),
+        };
 
-        for (i, &byte) in $eui.0.iter().enumerate() {
-            if i != 0 {
-                vec.push('-' as u8).expect("Vector is not long enough");
-            }
+        EUI64
+    }};
+}
 
-            vec.push(UPPERCASE_HEX_CHARS[(byte >> 4) as usize])
-                .expect("Vector is not long enough");
+/// Extended unique identifier of `N` octets, the first three holding the OUI.
+///
+/// Code shared by all widths lives here, the [`Eui48`] and [`Eui64`] aliases
+/// add what is specific to each of them.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Unaligned,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+#[repr(transparent)]
+pub struct Eui<const N: usize>([u8; N]);
+
+/// EUI-48, e.g. an Ethernet MAC address.
+pub type Eui48 = Eui<6>;
+
+/// EUI-64, e.g. an IEEE 802.15.4 extended address.
+pub type Eui64 = Eui<8>;
+
+/// Address of either width, for device lists mixing e.g. Ethernet and
+/// IEEE 802.15.4 nodes.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+pub enum LinkLayerAddr {
+    Eui48(Eui48),
+    Eui64(Eui64),
+}
 
-            vec.push(UPPERCASE_HEX_CHARS[(byte & 0xf) as usize])
-                .expect("Vector is not long enough");
-        }
+/// Longest short id, in octets, that fits in the returned string.
+const SHORT_ID_MAX_OCTETS: usize = 8;
 
-        unsafe { String::from_utf8_unchecked(vec) }
-    }};
+fn short_id(bytes: &[u8], octets: usize) -> String<16> {
+    let mut string = String::new();
+    let start = bytes.len() - octets.min(bytes.len()).min(SHORT_ID_MAX_OCTETS);
+
+    format::write_grouped(&mut string, &bytes[start..], 0, '-', LOWERCASE_HEX_CHARS)
+        .expect("String is not long enough");
+
+    string
 }
 
-impl Eui48 {
-    #[inline]
-    pub fn to_string(&self) -> String<U17> {
-        to_hex_string!(self, U17)
+fn prefixed_short_id<const N: usize>(
+    bytes: &[u8],
+    prefix: &str,
+    octets: usize,
+) -> Option<String<N>> {
+    let mut string = String::new();
+
+    string.push_str(prefix).ok()?;
+    string.push('-').ok()?;
+    string.push_str(&short_id(bytes, octets)).ok()?;
+
+    Some(string)
+}
+
+const fn compare_bytes(a: &[u8], b: &[u8]) -> Ordering {
+    let mut i = 0;
+
+    while i < a.len() && i < b.len() {
+        if a[i] < b[i] {
+            return Ordering::Less;
+        }
+
+        if a[i] > b[i] {
+            return Ordering::Greater;
+        }
+
+        i += 1;
     }
+
+    Ordering::Equal
 }
 
-impl Eui64 {
-    #[inline]
-    pub fn to_string(&self) -> String<U23> {
-        to_hex_string!(self, U23)
+const fn all_bytes_eq(bytes: &[u8], value: u8) -> bool {
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] != value {
+            return false;
+        }
+
+        i += 1;
     }
+
+    true
 }
 
-impl From<u64> for Eui48 {
-    fn from(value: u64) -> Self {
-        let b1: u8 = ((value >> 40) & 0xff) as u8;
-        let b2: u8 = ((value >> 32) & 0xff) as u8;
-        let b3: u8 = ((value >> 24) & 0xff) as u8;
-        let b4: u8 = ((value >> 16) & 0xff) as u8;
-        let b5: u8 = ((value >> 8) & 0xff) as u8;
-        let b6: u8 = (value & 0xff) as u8;
+/// Jump consistent hash by Lamping and Veach, see https://arxiv.org/abs/1406.2294.
+fn jump_consistent_hash(mut key: u64, buckets: u32) -> u32 {
+    assert!(buckets > 0, "Number of buckets must be positive");
+
+    let mut b: i64 = -1;
+    let mut j: i64 = 0;
 
-        return Eui48([b1, b2, b3, b4, b5, b6]);
+    while j < buckets as i64 {
+        b = j;
+        key = key.wrapping_mul(2862933555777941757).wrapping_add(1);
+        j = ((b + 1) as f64 * ((1u64 << 31) as f64 / ((key >> 33) + 1) as f64)) as i64;
     }
+
+    b as u32
 }
 
-impl From<u64> for Eui64 {
-    fn from(value: u64) -> Self {
-        Eui64(value.to_be_bytes())
+/// CRC-32 of the Ethernet frame check sequence, bitwise to save the table.
+const fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffff;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        crc ^= bytes[i] as u32;
+
+        let mut bit = 0;
+
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                crc >> 1 ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+
+        i += 1;
     }
+
+    !crc
 }
 
-/// Possible errors while converting string to eui.
-#[derive(Debug, PartialEq, Eq)]
-pub enum StringToEuiError {
-    InvalidLength { length: usize },
-    InvalidChar { char: char },
-    InvalidSeparatorPlace,
-    OnlyOneSeparatorTypeExpected,
+impl<const N: usize> Eui<N> {
+    /// Referenced by the constructors and the accessors reading the OUI, so
+    /// an address shorter than the OUI fails to compile instead of panicking.
+    pub(crate) const HOLDS_OUI: () =
+        assert!(N >= 3, "EUI must hold at least the 3 octets of the OUI");
+
+    pub const BROADCAST: Self = Self::from_bytes([0xff; N]);
+    pub const NIL: Self = Self::from_bytes([0x00; N]);
+
+    /// # Compile errors
+    ///
+    /// Fails to compile for `N` below 3, e.g.
+    ///
+    /// ```compile_fail
+    /// let eui = eui::Eui::<2>::from_bytes([0x4d, 0x7e]);
+    /// ```
+    #[inline]
+    pub const fn from_bytes(bytes: [u8; N]) -> Self {
+        let () = Self::HOLDS_OUI;
+
+        Eui(bytes)
+    }
+
+    /// Same as `TryFrom<&str>`, usable in constant expressions, see also
+    /// [`eui48!`] and [`eui64!`].
+    #[inline]
+    pub const fn parse_str(value: &str) -> Result<Self, ParseEuiError> {
+        Self::parse_ascii(value.as_bytes())
+    }
+
+    /// Same as [`parse_str`](Self::parse_str) on raw bytes, e.g. straight from
+    /// an AT command response, without checking for valid UTF-8 first.
+    pub const fn parse_ascii(value: &[u8]) -> Result<Self, ParseEuiError> {
+        let () = Self::HOLDS_OUI;
+
+        // Unseparated, Cisco dotted and separated after every octet.
+        if value.len() != 2 * N && value.len() != 2 * N + N / 2 - 1 && value.len() != 3 * N - 1 {
+            return Err(ParseEuiError::InvalidLength {
+                length: value.len(),
+            });
+        }
+
+        let mut result = [0; N];
+
+        match string_to_eui(value, &mut result) {
+            Ok(()) => Ok(Eui(result)),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Strict counterpart of `to_ieee_canonical()`, rejecting lowercase
+    /// digits, colons and unseparated input.
+    pub fn from_ieee_canonical(value: &str) -> Result<Self, ParseEuiError> {
+        let mut result = [0; N];
+        ieee_canonical_to_eui(value, &mut result[..])?;
+
+        Ok(Self::from_bytes(result))
+    }
+
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8; N] {
+        &self.0
+    }
+
+    #[inline]
+    pub const fn into_bytes(self) -> [u8; N] {
+        self.0
+    }
+
+    /// Same order as `Ord`, usable in constant expressions, e.g. to check at
+    /// compile time that a static table is sorted for binary search.
+    pub const fn compare(&self, other: &Self) -> Ordering {
+        compare_bytes(&self.0, &other.0)
+    }
+
+    /// Individual address, I/G bit of the first octet cleared.
+    #[inline]
+    pub const fn is_unicast(&self) -> bool {
+        let () = Self::HOLDS_OUI;
+
+        self.0[0] & 0x01 == 0
+    }
+
+    /// Group address, I/G bit of the first octet set.
+    #[inline]
+    pub const fn is_multicast(&self) -> bool {
+        !self.is_unicast()
+    }
+
+    #[inline]
+    pub const fn is_broadcast(&self) -> bool {
+        all_bytes_eq(&self.0, 0xff)
+    }
+
+    /// Globally unique address, U/L bit of the first octet cleared.
+    #[inline]
+    pub const fn is_universal(&self) -> bool {
+        let () = Self::HOLDS_OUI;
+
+        self.0[0] & 0x02 == 0
+    }
+
+    /// Locally administered address, U/L bit of the first octet set.
+    #[inline]
+    pub const fn is_local(&self) -> bool {
+        !self.is_universal()
+    }
+
+    #[inline]
+    pub const fn is_nil(&self) -> bool {
+        all_bytes_eq(&self.0, 0x00)
+    }
+
+    #[inline]
+    pub const fn set_local(&mut self, local: bool) {
+        let () = Self::HOLDS_OUI;
+
+        if local {
+            self.0[0] |= 0x02
+        } else {
+            self.0[0] &= !0x02
+        }
+    }
+
+    #[inline]
+    pub const fn set_multicast(&mut self, multicast: bool) {
+        let () = Self::HOLDS_OUI;
+
+        if multicast {
+            self.0[0] |= 0x01
+        } else {
+            self.0[0] &= !0x01
+        }
+    }
+
+    #[inline]
+    pub const fn toggle_universal_local(&mut self) {
+        let () = Self::HOLDS_OUI;
+
+        self.0[0] ^= 0x02
+    }
+
+    #[inline]
+    pub const fn toggle_unicast_multicast(&mut self) {
+        let () = Self::HOLDS_OUI;
+
+        self.0[0] ^= 0x01
+    }
+
+    #[inline]
+    pub const fn with_local(mut self) -> Self {
+        self.set_local(true);
+        self
+    }
+
+    #[inline]
+    pub const fn with_universal(mut self) -> Self {
+        self.set_local(false);
+        self
+    }
+
+    #[inline]
+    pub const fn with_multicast(mut self) -> Self {
+        self.set_multicast(true);
+        self
+    }
+
+    #[inline]
+    pub const fn with_unicast(mut self) -> Self {
+        self.set_multicast(false);
+        self
+    }
+
+    /// Formats into `buf` without going through a `String`, returning the
+    /// written part. Digits are uppercase for [`EuiFormat::Hyphen`] and
+    /// lowercase otherwise; `3 * N - 1` bytes always suffice.
+    pub fn write_hex<'a>(
+        &self,
+        buf: &'a mut [u8],
+        format: EuiFormat,
+    ) -> Result<&'a str, BufferTooSmall> {
+        format::write_hex(buf, &self.0, format)
+    }
+
+    /// Last `octets` octets as lowercase hex, e.g. `972eef` for three octets.
+    /// Values above `N` or above 8 are clamped, so an address longer than an
+    /// EUI-64 gives at most its last 8 octets.
+    pub fn short_id(&self, octets: usize) -> String<16> {
+        short_id(&self.0, octets)
+    }
+
+    /// Short id joined to `prefix` with a hyphen, e.g. `sensor-972eef`, suitable
+    /// for default hostnames, SSIDs or BLE names. `None` if it doesn't fit in `L`.
+    pub fn prefixed_short_id<const L: usize>(
+        &self,
+        prefix: &str,
+        octets: usize,
+    ) -> Option<String<L>> {
+        prefixed_short_id(&self.0, prefix, octets)
+    }
+
+    /// Organizationally unique identifier (first three octets) as a 24-bit value.
+    pub const fn oui_u32(&self) -> u32 {
+        let () = Self::HOLDS_OUI;
+
+        u32::from_be_bytes([0, self.0[0], self.0[1], self.0[2]])
+    }
+
+    /// Hex digits in groups of `group_size` separated by `separator`, e.g.
+    /// `grouped(4, '.')` for `4D7E.5497.2EEF`.
+    pub fn grouped(&self, group_size: usize, separator: char) -> Grouped<'_> {
+        Grouped {
+            bytes: &self.0,
+            group_size,
+            separator,
+        }
+    }
+
+    /// Same vendor prefix with the rest zeroed, e.g. to group log entries by
+    /// vendor without identifying the device.
+    pub const fn anonymized(&self) -> Self {
+        let () = Self::HOLDS_OUI;
+
+        let mut bytes = [0; N];
+        let mut i = 0;
+
+        while i < 3 {
+            bytes[i] = self.0[i];
+            i += 1;
+        }
+
+        Eui(bytes)
+    }
+
+    /// Prints only the OUI, e.g. `4d:7e:54:xx:xx:xx`, for logs that must not
+    /// identify the device.
+    pub fn redacted(&self) -> Redacted<'_> {
+        let () = Self::HOLDS_OUI;
+
+        Redacted { bytes: &self.0 }
+    }
 }
 
-pub(crate) fn string_to_eui(input: &str, result: &mut [u8]) -> Result<(), StringToEuiError> {
-    let mut separator_type = None;
-    let mut separators = 0;
+impl Eui48 {
+    /// Same as `From<u64>`, usable in constant expressions. The upper 16 bits
+    /// of `value` are ignored.
+    #[inline]
+    pub const fn new(value: u64) -> Self {
+        let [_, _, b1, b2, b3, b4, b5, b6] = value.to_be_bytes();
 
-    for (i, c) in input.chars().enumerate() {
-        let char_byte = c as u8;
+        Eui([b1, b2, b3, b4, b5, b6])
+    }
 
-        let hex_char_index = match char_byte {
-            b'A'..=b'F' => Some(char_byte - b'A' + 10),
-            b'a'..=b'f' => Some(char_byte - b'a' + 10),
-            b'0'..=b'9' => Some(char_byte - b'0'),
-            _ => None,
-        };
+    /// Fails instead of truncating values above 48 bits, e.g. to detect
+    /// corrupted numeric addresses in configuration.
+    ///
+    /// This isn't `TryFrom<u64>`, which conflicts with `From<u64>` through the
+    /// blanket impl in `core`.
+    pub const fn try_from_u64(value: u64) -> Result<Self, Eui48RangeError> {
+        if value > 0xffff_ffff_ffff {
+            return Err(Eui48RangeError);
+        }
 
-        match hex_char_index {
-            Some(value) => {
-                let current_pos = i - separators;
-                let index = current_pos / 2;
+        Ok(Eui48::new(value))
+    }
 
-                if index > result.len() - 1 {
-                    return Err(StringToEuiError::InvalidLength {
-                        length: input.len() - separators,
-                    });
-                }
+    /// Same as `From<u64>`: the upper 16 bits of `value` are dropped.
+    #[inline]
+    pub const fn from_u64_lossy(value: u64) -> Self {
+        Eui48::new(value)
+    }
 
-                if current_pos % 2 == 0 {
-                    result[index] = (value as u8) << 4 & 0xF0
-                } else {
-                    result[index] |= value as u8 & 0xF
-                }
-            }
-            None if c == ':' || c == '-' => {
-                // String may contain separator after every second character.
-                if i == 0 || i == input.len() || (i + 1) % 3 != 0 {
-                    return Err(StringToEuiError::InvalidSeparatorPlace);
-                }
+    #[inline]
+    pub fn to_string(&self) -> String<17> {
+        self.format(EuiFormat::Hyphen, Case::Upper)
+    }
 
-                match separator_type {
-                    Some(t) => {
-                        if t != c {
-                            return Err(StringToEuiError::OnlyOneSeparatorTypeExpected);
-                        }
-                    }
-                    None => separator_type = Some(c),
-                }
+    /// IEEE 802 canonical form: uppercase hex octets separated by hyphens.
+    #[inline]
+    pub fn to_ieee_canonical(&self) -> String<17> {
+        self.format(EuiFormat::Hyphen, Case::Upper)
+    }
 
-                separators += 1;
-            }
-            None => {
-                return Err(StringToEuiError::InvalidChar { char: c });
-            }
+    pub fn format(&self, format: EuiFormat, case: Case) -> String<17> {
+        let mut string = String::new();
+
+        // Separated after every octet is the longest format, 3 * 6 - 1 long.
+        format::write_formatted(&mut string, &self.0, format, case)
+            .expect("String is not long enough");
+
+        string
+    }
+
+    /// Network interface controller specific part (last three octets) as a 24-bit value.
+    pub const fn nic_u32(&self) -> u32 {
+        u32::from_be_bytes([0, self.0[3], self.0[4], self.0[5]])
+    }
+
+    /// Adds `n` to the NIC specific part, failing instead of carrying into the OUI.
+    pub fn checked_add_in_oui(&self, n: u64) -> Result<Self, NicOverflowError> {
+        let value = u64::from(*self);
+
+        match (value & 0xff_ffff).checked_add(n) {
+            Some(nic) if nic <= 0xff_ffff => Ok(Eui48::from(value & !0xff_ffff | nic)),
+            _ => Err(NicOverflowError),
         }
     }
 
-    Ok(())
+    #[inline]
+    pub fn checked_next_in_oui(&self) -> Result<Self, NicOverflowError> {
+        self.checked_add_in_oui(1)
+    }
+
+    /// Maps the address to a bucket in `0..n_buckets`.
+    ///
+    /// Uses jump consistent hashing over the address as a big-endian integer,
+    /// so the mapping is stable across releases and growing `n_buckets` by one
+    /// only moves about `1 / n_buckets` of the addresses.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n_buckets` is zero.
+    pub fn bucket(&self, n_buckets: u32) -> u32 {
+        jump_consistent_hash(u64::from(*self), n_buckets)
+    }
+
+    /// CRC-32 of the octets as used for the Ethernet FCS, equal to the
+    /// common `crc32` of zlib.
+    #[inline]
+    pub const fn hash_crc32(&self) -> u32 {
+        crc32(&self.0)
+    }
+
+    /// Bit index of the address in a multicast hash filter with `n` bins,
+    /// the upper `log2(n)` bits of the bit-reversed, complemented CRC-32.
+    ///
+    /// This matches `ether_crc(6, addr) >> 26` of the Linux drivers for MACs
+    /// with 64-bit hash table registers, e.g. as `hash_to_bucket(64)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is not a power of two.
+    pub const fn hash_to_bucket(&self, n: u32) -> u32 {
+        assert!(n.is_power_of_two(), "Number of bins must be a power of two");
+
+        match n.trailing_zeros() {
+            0 => 0,
+            bits => (!self.hash_crc32()).reverse_bits() >> (32 - bits),
+        }
+    }
+
+    /// Modified EUI-64 as of RFC 4291 Appendix A: `FF-FE` inserted after the
+    /// OUI and the universal/local bit inverted, as used for SLAAC.
+    pub const fn to_modified_eui64(&self) -> Eui64 {
+        let b = self.0;
+
+        Eui([b[0] ^ 0x02, b[1], b[2], 0xff, 0xfe, b[3], b[4], b[5]])
+    }
+
+    /// `00-00` inserted after the OUI, as done by `Eui64::from`.
+    pub const fn to_eui64_zero_padded(&self) -> Eui64 {
+        let b = self.0;
+
+        Eui([b[0], b[1], b[2], 0x00, 0x00, b[3], b[4], b[5]])
+    }
 }
 
-impl TryFrom<&str> for Eui48 {
-    type Error = StringToEuiError;
+impl Eui64 {
+    /// Same as `From<u64>`, usable in constant expressions.
+    #[inline]
+    pub const fn new(value: u64) -> Self {
+        Eui(value.to_be_bytes())
+    }
 
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if value.len() != 12 && value.len() != 17 {
-            return Err(StringToEuiError::InvalidLength {
-                length: value.len(),
-            });
+    /// Same as [`from_bytes`](Self::from_bytes), spelling out that the first
+    /// octet, holding the OUI, comes first.
+    #[inline]
+    pub const fn from_be_bytes(bytes: [u8; 8]) -> Self {
+        Eui(bytes)
+    }
+
+    /// Address sent with the last octet first, as done for IEEE 802.15.4 long
+    /// addresses and the LoRaWAN DevEUI and JoinEUI.
+    pub const fn from_le_bytes(bytes: [u8; 8]) -> Self {
+        Eui(bytes).reversed()
+    }
+
+    #[inline]
+    pub const fn to_be_bytes(&self) -> [u8; 8] {
+        self.0
+    }
+
+    /// Octets in wire order of IEEE 802.15.4 and LoRaWAN, the last one first.
+    pub const fn to_le_bytes(&self) -> [u8; 8] {
+        self.reversed().0
+    }
+
+    /// Address with the octet order reversed, e.g. to fix one read in the
+    /// wrong byte order.
+    pub const fn reversed(&self) -> Self {
+        let [b1, b2, b3, b4, b5, b6, b7, b8] = self.0;
+
+        Eui([b8, b7, b6, b5, b4, b3, b2, b1])
+    }
+
+    #[inline]
+    pub fn to_string(&self) -> String<23> {
+        self.format(EuiFormat::Hyphen, Case::Upper)
+    }
+
+    /// IEEE 802 canonical form: uppercase hex octets separated by hyphens.
+    #[inline]
+    pub fn to_ieee_canonical(&self) -> String<23> {
+        self.format(EuiFormat::Hyphen, Case::Upper)
+    }
+
+    pub fn format(&self, format: EuiFormat, case: Case) -> String<23> {
+        let mut string = String::new();
+
+        // Separated after every octet is the longest format, 3 * 8 - 1 long.
+        format::write_formatted(&mut string, &self.0, format, case)
+            .expect("String is not long enough");
+
+        string
+    }
+
+    /// Adds `n` to the 40-bit extension identifier, failing instead of carrying
+    /// into the OUI.
+    pub fn checked_add_in_oui(&self, n: u64) -> Result<Self, NicOverflowError> {
+        let value = u64::from(*self);
+
+        match (value & 0xff_ffff_ffff).checked_add(n) {
+            Some(nic) if nic <= 0xff_ffff_ffff => Ok(Eui64::from(value & !0xff_ffff_ffff | nic)),
+            _ => Err(NicOverflowError),
         }
+    }
+
+    #[inline]
+    pub fn checked_next_in_oui(&self) -> Result<Self, NicOverflowError> {
+        self.checked_add_in_oui(1)
+    }
+
+    /// Maps the address to a bucket in `0..n_buckets`.
+    ///
+    /// Uses jump consistent hashing over the address as a big-endian integer,
+    /// so the mapping is stable across releases and growing `n_buckets` by one
+    /// only moves about `1 / n_buckets` of the addresses.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n_buckets` is zero.
+    pub fn bucket(&self, n_buckets: u32) -> u32 {
+        jump_consistent_hash(u64::from(*self), n_buckets)
+    }
+
+    /// IPv6 interface identifier, i.e. modified EUI-64 as of RFC 4291 Appendix A:
+    /// the universal/local bit inverted.
+    pub const fn to_ipv6_interface_id(&self) -> [u8; 8] {
+        let mut bytes = self.0;
+        bytes[0] ^= 0x02;
+
+        bytes
+    }
+
+    /// Segments of the link-local IPv6 address `fe80::/64` with this address'
+    /// interface identifier, as taken by `Ipv6Addr::new`.
+    pub const fn to_link_local_parts(&self) -> [u16; 8] {
+        let id = self.to_ipv6_interface_id();
+
+        [
+            0xfe80,
+            0,
+            0,
+            0,
+            u16::from_be_bytes([id[0], id[1]]),
+            u16::from_be_bytes([id[2], id[3]]),
+            u16::from_be_bytes([id[4], id[5]]),
+            u16::from_be_bytes([id[6], id[7]]),
+        ]
+    }
+}
+
+impl From<u64> for Eui48 {
+    fn from(value: u64) -> Self {
+        Eui48::new(value)
+    }
+}
+
+impl From<u64> for Eui64 {
+    fn from(value: u64) -> Self {
+        Eui64::new(value)
+    }
+}
 
-        let mut result = [0; 6];
-        string_to_eui(value, &mut result[..])?;
+impl<const N: usize> From<[u8; N]> for Eui<N> {
+    fn from(bytes: [u8; N]) -> Self {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl<const N: usize> From<Eui<N>> for [u8; N] {
+    fn from(eui: Eui<N>) -> Self {
+        eui.0
+    }
+}
+
+impl<const N: usize> TryFrom<&[u8]> for Eui<N> {
+    type Error = TryFromSliceError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        <[u8; N]>::try_from(value).map(Self::from_bytes)
+    }
+}
+
+impl From<(u8, u8, u8, u8, u8, u8)> for Eui48 {
+    fn from((b1, b2, b3, b4, b5, b6): (u8, u8, u8, u8, u8, u8)) -> Self {
+        Eui([b1, b2, b3, b4, b5, b6])
+    }
+}
+
+impl From<(u8, u8, u8, u8, u8, u8, u8, u8)> for Eui64 {
+    fn from((b1, b2, b3, b4, b5, b6, b7, b8): (u8, u8, u8, u8, u8, u8, u8, u8)) -> Self {
+        Eui([b1, b2, b3, b4, b5, b6, b7, b8])
+    }
+}
+
+impl From<Eui48> for (u8, u8, u8, u8, u8, u8) {
+    fn from(eui48: Eui48) -> Self {
+        let [b1, b2, b3, b4, b5, b6] = eui48.0;
+
+        (b1, b2, b3, b4, b5, b6)
+    }
+}
+
+impl From<Eui64> for (u8, u8, u8, u8, u8, u8, u8, u8) {
+    fn from(eui64: Eui64) -> Self {
+        let [b1, b2, b3, b4, b5, b6, b7, b8] = eui64.0;
+
+        (b1, b2, b3, b4, b5, b6, b7, b8)
+    }
+}
+
+/// Possible errors while converting string to eui.
+///
+/// Positions are byte offsets into the input.
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseEuiError {
+    InvalidLength {
+        length: usize,
+    },
+    InvalidChar {
+        char: char,
+        position: usize,
+    },
+    InvalidSeparatorPlace {
+        position: usize,
+    },
+    OnlyOneSeparatorTypeExpected {
+        position: usize,
+    },
+    /// Output slice passed to `parse_list()` is full, `position` is the start
+    /// of the first address left over.
+    TooManyAddresses {
+        position: usize,
+    },
+}
+
+impl ParseEuiError {
+    /// Stable numeric code, e.g. for error replies of a CLI.
+    pub const fn code(&self) -> u8 {
+        match self {
+            ParseEuiError::InvalidLength { .. } => 1,
+            ParseEuiError::InvalidChar { .. } => 2,
+            ParseEuiError::InvalidSeparatorPlace { .. } => 3,
+            ParseEuiError::OnlyOneSeparatorTypeExpected { .. } => 4,
+            ParseEuiError::TooManyAddresses { .. } => 5,
+        }
+    }
+
+    /// Byte offset of the offending character, `None` for length errors.
+    pub const fn position(&self) -> Option<usize> {
+        match self {
+            ParseEuiError::InvalidLength { .. } => None,
+            ParseEuiError::InvalidChar { position, .. }
+            | ParseEuiError::InvalidSeparatorPlace { position }
+            | ParseEuiError::OnlyOneSeparatorTypeExpected { position }
+            | ParseEuiError::TooManyAddresses { position } => Some(*position),
+        }
+    }
+
+    /// Same error for input starting `offset` bytes into a longer string.
+    pub(crate) fn offset_by(self, offset: usize) -> Self {
+        match self {
+            ParseEuiError::InvalidLength { length } => ParseEuiError::InvalidLength { length },
+            ParseEuiError::InvalidChar { char, position } => ParseEuiError::InvalidChar {
+                char,
+                position: position + offset,
+            },
+            ParseEuiError::InvalidSeparatorPlace { position } => {
+                ParseEuiError::InvalidSeparatorPlace {
+                    position: position + offset,
+                }
+            }
+            ParseEuiError::OnlyOneSeparatorTypeExpected { position } => {
+                ParseEuiError::OnlyOneSeparatorTypeExpected {
+                    position: position + offset,
+                }
+            }
+            ParseEuiError::TooManyAddresses { position } => ParseEuiError::TooManyAddresses {
+                position: position + offset,
+            },
+        }
+    }
+}
+
+/// Former name of [`ParseEuiError`].
+pub type StringToEuiError = ParseEuiError;
+
+/// EUI-64 doesn't encapsulate an EUI-48: octets four and five are neither
+/// `00-00` nor `FF-FE`.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Eui64ToEui48Error;
+
+/// Arithmetic on the NIC specific part ran past the end of the OUI block.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct NicOverflowError;
+
+/// Buffer passed to `write_hex()` can't hold the formatted address.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct BufferTooSmall;
+
+/// Integer passed to `Eui48::try_from_u64()` doesn't fit in 48 bits.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Eui48RangeError;
+
+/// Extension passed to `Eui64::from_parts()` doesn't fit in 40 bits.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct ExtensionRangeError;
+
+impl Display for ParseEuiError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self {
+            ParseEuiError::InvalidLength { length } => write!(f, "Invalid length {}", length),
+            ParseEuiError::InvalidChar { char, position } => {
+                write!(f, "Invalid character `{}` at position {}", char, position)
+            }
+            ParseEuiError::InvalidSeparatorPlace { position } => write!(
+                f,
+                "Separator must be placed after every second character, found one at position {}",
+                position
+            ),
+            ParseEuiError::OnlyOneSeparatorTypeExpected { position } => write!(
+                f,
+                "Only one type of separator should be used, found another at position {}",
+                position
+            ),
+            ParseEuiError::TooManyAddresses { position } => write!(
+                f,
+                "Too many addresses, no space left for the one at position {}",
+                position
+            ),
+        }
+    }
+}
+
+impl Display for Eui64ToEui48Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_str("EUI-64 doesn't encapsulate an EUI-48")
+    }
+}
+
+impl Display for NicOverflowError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_str("NIC specific part overflowed")
+    }
+}
+
+impl Display for BufferTooSmall {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_str("Buffer is too small for the formatted address")
+    }
+}
+
+impl Display for Eui48RangeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_str("Value doesn't fit in 48 bits")
+    }
+}
+
+impl Display for ExtensionRangeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_str("Extension identifier doesn't fit in 40 bits")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseEuiError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Eui64ToEui48Error {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NicOverflowError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BufferTooSmall {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Eui48RangeError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ExtensionRangeError {}
+
+/// Parses hex digits into `result`, either unseparated, with `:`, `-` or space
+/// after every second digit, or with `.` after every fourth as done by Cisco.
+///
+/// Works on bytes rather than chars so it can run in constant expressions.
+pub(crate) const fn string_to_eui(input: &[u8], result: &mut [u8]) -> Result<(), ParseEuiError> {
+    let mut separator_type = 0;
+    let mut separators = 0;
+    let mut i = 0;
+
+    while i < input.len() {
+        let c = input[i];
+
+        let hex_char_index = match c {
+            b'A'..=b'F' => Some(c - b'A' + 10),
+            b'a'..=b'f' => Some(c - b'a' + 10),
+            b'0'..=b'9' => Some(c - b'0'),
+            _ => None,
+        };
+
+        match hex_char_index {
+            Some(value) => {
+                // Once separators are used, every group has to be followed by one.
+                let group_size = if separator_type == b'.' { 4 } else { 2 };
+
+                if separator_type != 0 && (i + 1) % (group_size + 1) == 0 {
+                    return Err(ParseEuiError::InvalidSeparatorPlace { position: i });
+                }
+
+                let current_pos = i - separators;
+                let index = current_pos / 2;
+
+                if index > result.len() - 1 {
+                    return Err(ParseEuiError::InvalidLength {
+                        length: input.len() - separators,
+                    });
+                }
+
+                if current_pos % 2 == 0 {
+                    result[index] = value << 4 & 0xF0
+                } else {
+                    result[index] |= value & 0xF
+                }
+            }
+            None if c == b':' || c == b'-' || c == b' ' || c == b'.' => {
+                let group_size = if c == b'.' { 4 } else { 2 };
+                let slot = (i + 1) / (group_size + 1);
+
+                // String may contain separator after every group of characters,
+                // starting with the first and not after the last.
+                if i == 0
+                    || (i + 1) % (group_size + 1) != 0
+                    || slot != separators + 1
+                    || slot >= result.len() * 2 / group_size
+                {
+                    return Err(ParseEuiError::InvalidSeparatorPlace { position: i });
+                }
+
+                if separator_type == 0 {
+                    separator_type = c;
+                } else if separator_type != c {
+                    return Err(ParseEuiError::OnlyOneSeparatorTypeExpected { position: i });
+                }
+
+                separators += 1;
+            }
+            None => {
+                return Err(ParseEuiError::InvalidChar {
+                    char: char_at(input, i),
+                    position: i,
+                });
+            }
+        }
+
+        i += 1;
+    }
+
+    if input.len() - separators != result.len() * 2 {
+        return Err(ParseEuiError::InvalidLength {
+            length: input.len() - separators,
+        });
+    }
+
+    Ok(())
+}
+
+/// Decodes the UTF-8 encoded char starting at `input[i]`, or gives the
+/// replacement character if there is none.
+const fn char_at(input: &[u8], i: usize) -> char {
+    let lead = input[i] as u32;
+
+    let (length, mut code) = if lead < 0x80 {
+        (1, lead)
+    } else if lead < 0xc0 || lead >= 0xf8 {
+        return char::REPLACEMENT_CHARACTER;
+    } else if lead < 0xe0 {
+        (2, lead & 0x1f)
+    } else if lead < 0xf0 {
+        (3, lead & 0x0f)
+    } else {
+        (4, lead & 0x07)
+    };
+
+    if i + length > input.len() {
+        return char::REPLACEMENT_CHARACTER;
+    }
+
+    let mut j = 1;
+
+    while j < length {
+        // Raw bytes passed to `parse_ascii()` need not be valid UTF-8.
+        if input[i + j] & 0xc0 != 0x80 {
+            return char::REPLACEMENT_CHARACTER;
+        }
+
+        code = code << 6 | (input[i + j] as u32 & 0x3f);
+        j += 1;
+    }
+
+    match char::from_u32(code) {
+        Some(c) => c,
+        None => char::REPLACEMENT_CHARACTER,
+    }
+}
+
+fn ieee_canonical_to_eui(input: &str, result: &mut [u8]) -> Result<(), ParseEuiError> {
+    if input.len() != result.len() * 3 - 1 {
+        return Err(ParseEuiError::InvalidLength {
+            length: input.len(),
+        });
+    }
+
+    for (i, c) in input.char_indices() {
+        if (i + 1) % 3 == 0 {
+            if c != '-' {
+                return Err(ParseEuiError::InvalidChar {
+                    char: c,
+                    position: i,
+                });
+            }
+
+            continue;
+        }
+
+        let value = match c {
+            'A'..='F' => c as u8 - b'A' + 10,
+            '0'..='9' => c as u8 - b'0',
+            '-' => return Err(ParseEuiError::InvalidSeparatorPlace { position: i }),
+            _ => {
+                return Err(ParseEuiError::InvalidChar {
+                    char: c,
+                    position: i,
+                })
+            }
+        };
+
+        let index = i / 3;
+
+        if i % 3 == 0 {
+            result[index] = value << 4
+        } else {
+            result[index] |= value
+        }
+    }
+
+    Ok(())
+}
+
+impl<const N: usize> TryFrom<&str> for Eui<N> {
+    type Error = ParseEuiError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Eui::parse_str(value)
+    }
+}
+
+impl<const N: usize> FromStr for Eui<N> {
+    type Err = ParseEuiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Eui::try_from(s)
+    }
+}
+
+impl From<Eui48> for Eui64 {
+    /// Same as [`Eui48::to_eui64_zero_padded`], see
+    /// [`Eui48::to_modified_eui64`] for deriving IPv6 interface identifiers.
+    fn from(eui48: Eui48) -> Self {
+        eui48.to_eui64_zero_padded()
+    }
+}
+
+impl TryFrom<Eui64> for Eui48 {
+    type Error = Eui64ToEui48Error;
+
+    /// Reverses both [`Eui48::to_eui64_zero_padded`] and the IEEE `FF-FE`
+    /// encapsulation.
+    fn try_from(eui64: Eui64) -> Result<Self, Self::Error> {
+        let b = eui64.0;
+
+        match (b[3], b[4]) {
+            (0x00, 0x00) | (0xff, 0xfe) => Ok(Eui([b[0], b[1], b[2], b[5], b[6], b[7]])),
+            _ => Err(Eui64ToEui48Error),
+        }
+    }
+}
+
+impl From<Eui48> for u64 {
+    fn from(eui48: Eui48) -> Self {
+        let data = eui48.0;
+
+        ((data[0] as u64) << 40)
+            + ((data[1] as u64) << 32)
+            + ((data[2] as u64) << 24)
+            + ((data[3] as u64) << 16)
+            + ((data[4] as u64) << 8)
+            + (data[5] as u64)
+    }
+}
+
+impl From<Eui64> for u64 {
+    fn from(eui64: Eui64) -> Self {
+        u64::from_be_bytes(eui64.0)
+    }
+}
+
+impl<const N: usize> Debug for Eui<N> {
+    /// Same output as derived for the former `Eui48` and `Eui64` structs.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let name = match N {
+            6 => "Eui48",
+            8 => "Eui64",
+            _ => "Eui",
+        };
+
+        f.debug_tuple(name).field(&self.0).finish()
+    }
+}
+
+impl<const N: usize> Display for Eui<N> {
+    /// `{}` prints the IEEE form, `{:-}` the same in lowercase, `{:#}` the
+    /// colon separated lowercase form and `{:+}` the Cisco dotted form.
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        let (format, case) = format::display_format(f);
+
+        format::write_formatted(f, &self.0, format, case)
+    }
+}
+
+impl<const N: usize> UpperHex for Eui<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        format::fmt_hex(f, &self.0, Case::Upper)
+    }
+}
+
+impl<const N: usize> LowerHex for Eui<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        format::fmt_hex(f, &self.0, Case::Lower)
+    }
+}
+
+impl<const N: usize> Binary for Eui<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        format::fmt_binary(f, &self.0)
+    }
+}
+
+impl From<Eui48> for LinkLayerAddr {
+    fn from(eui48: Eui48) -> Self {
+        LinkLayerAddr::Eui48(eui48)
+    }
+}
+
+impl From<Eui64> for LinkLayerAddr {
+    fn from(eui64: Eui64) -> Self {
+        LinkLayerAddr::Eui64(eui64)
+    }
+}
+
+impl TryFrom<&str> for LinkLayerAddr {
+    type Error = ParseEuiError;
+
+    /// Width is detected from the length of the string.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.len() {
+            12 | 14 | 17 => Eui48::try_from(value).map(LinkLayerAddr::Eui48),
+            16 | 19 | 23 => Eui64::try_from(value).map(LinkLayerAddr::Eui64),
+            length => Err(ParseEuiError::InvalidLength { length }),
+        }
+    }
+}
+
+impl FromStr for LinkLayerAddr {
+    type Err = ParseEuiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LinkLayerAddr::try_from(s)
+    }
+}
+
+impl Display for LinkLayerAddr {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        match self {
+            LinkLayerAddr::Eui48(eui48) => Display::fmt(eui48, f),
+            LinkLayerAddr::Eui64(eui64) => Display::fmt(eui64, f),
+        }
+    }
+}
+
+#[test]
+fn test_eui48_to_string() {
+    let eui48 = Eui48::from(85204980412143);
+
+    assert_eq!(eui48.to_string(), "4D-7E-54-97-2E-EF")
+}
+
+#[test]
+fn test_eui64_to_string() {
+    let eui64 = Eui64::from(5583992946972634863);
+
+    assert_eq!(eui64.to_string(), "4D-7E-54-00-00-97-2E-EF")
+}
+
+#[test]
+fn test_eui48_to_eui64() {
+    let eui48 = Eui48::from(85204980412143);
+    let eui64 = Eui64::from(eui48);
+
+    assert_eq!(eui64.to_string(), "4D-7E-54-00-00-97-2E-EF")
+}
+
+#[test]
+fn test_u64_from_eui48() {
+    let eui48 = Eui48::from(85204980412143);
+    assert_eq!(u64::from(eui48), 85204980412143);
+}
+
+#[test]
+fn test_u64_from_eui64() {
+    let eui64 = Eui64::from(5583992946972634863);
+    assert_eq!(u64::from(eui64), 5583992946972634863);
+}
+
+#[test]
+fn test_hash_eui48() {
+    use heapless::FnvIndexMap;
+
+    let eui48 = Eui48::from(85204980412143);
+
+    let mut fnv_index_map: FnvIndexMap<Eui48, u8, 2> = FnvIndexMap::new();
+    fnv_index_map.insert(eui48, 1).unwrap();
+
+    assert_eq!(1, *fnv_index_map.get(&eui48).unwrap())
+}
+
+#[test]
+fn test_hash_eui64() {
+    use heapless::FnvIndexMap;
+
+    let eui64 = Eui64::from(5583992946972634863);
+
+    let mut fnv_index_map: FnvIndexMap<Eui64, u8, 2> = FnvIndexMap::new();
+    fnv_index_map.insert(eui64, 1).unwrap();
+
+    assert_eq!(1, *fnv_index_map.get(&eui64).unwrap())
+}
+
+#[test]
+fn test_display_eui48() {
+    extern crate std;
+    use std::format;
+
+    let eui48 = Eui48::from(85204980412143);
+
+    assert_eq!(format!("{}", eui48), "4D-7E-54-97-2E-EF");
+}
+
+#[test]
+fn test_display_eui64() {
+    extern crate std;
+    use std::format;
+
+    let eui64 = Eui64::from(5583992946972634863);
+
+    assert_eq!(format!("{}", eui64), "4D-7E-54-00-00-97-2E-EF");
+}
+
+#[test]
+fn test_format_upper_hex_eui48() {
+    extern crate std;
+    use std::format;
+
+    let eui48 = Eui48::from(85204980412143);
+
+    assert_eq!(format!("{:X}", eui48), "4D7E54972EEF");
+}
+
+#[test]
+fn test_format_upper_hex_eui64() {
+    extern crate std;
+    use std::format;
+
+    let eui64 = Eui64::from(5583992946972634863);
+
+    assert_eq!(format!("{:X}", eui64), "4D7E540000972EEF");
+}
+
+#[test]
+fn test_format_lower_hex_eui48() {
+    extern crate std;
+    use std::format;
+
+    let eui48 = Eui48::from(85204980412143);
+
+    assert_eq!(format!("{:x}", eui48), "4d7e54972eef");
+}
+
+#[test]
+fn test_format_lower_hex_eui64() {
+    extern crate std;
+    use std::format;
+
+    let eui64 = Eui64::from(5583992946972634863);
+
+    assert_eq!(format!("{:x}", eui64), "4d7e540000972eef");
+}
+
+#[test]
+fn test_format_hex_leading_zeros() {
+    extern crate std;
+    use std::format;
+
+    let eui48 = Eui48::from(0x00005E0053AF);
+    let eui64 = Eui64::from(1);
+
+    assert_eq!(format!("{:X}", eui48), "00005E0053AF");
+    assert_eq!(format!("{:#x}", eui48), "0x00005e0053af");
+    assert_eq!(format!("{:x}", eui64), "0000000000000001");
+}
+
+#[test]
+fn test_format_binary() {
+    extern crate std;
+    use std::format;
+
+    let eui48 = Eui48::from(0x0300000000FF);
+    let eui64 = Eui64::from(0x8000000000000001);
+
+    assert_eq!(
+        format!("{:b}", eui48),
+        "000000110000000000000000000000000000000011111111"
+    );
+    assert_eq!(
+        format!("{:#b}", eui64),
+        "0b1000000000000000000000000000000000000000000000000000000000000001"
+    );
+}
+
+#[test]
+fn test_eui48_try_from_string() {
+    let eui48 = Eui48::try_from("4D7E54972EEF").unwrap();
+
+    assert_eq!(u64::from(eui48), 85204980412143);
+}
+
+#[test]
+fn test_eui64_try_from_string() {
+    let eui64 = Eui64::try_from("4D7E540000972EEF").unwrap();
+
+    assert_eq!(u64::from(eui64), 5583992946972634863);
+}
+
+#[test]
+fn test_eui48_try_from_string_with_separator() {
+    let eui48_1 = Eui48::try_from("4D-7E-54-97-2E-EF").unwrap();
+    let eui48_2 = Eui48::try_from("4D:7E:54:97:2E:EF").unwrap();
+
+    assert_eq!(u64::from(eui48_1), 85204980412143);
+    assert_eq!(u64::from(eui48_2), 85204980412143);
+}
+
+#[test]
+fn test_eui64_try_from_string_with_separator() {
+    let eui64_1 = Eui64::try_from("4D-7E-54-00-00-97-2E-EF").unwrap();
+    let eui64_2 = Eui64::try_from("4D:7E:54:00:00:97:2E:EF").unwrap();
+
+    assert_eq!(u64::from(eui64_1), 5583992946972634863);
+    assert_eq!(u64::from(eui64_2), 5583992946972634863);
+}
+
+#[test]
+fn test_eui48_try_from_invalid_length() {
+    assert_eq!(
+        Eui48::try_from("").err().unwrap(),
+        StringToEuiError::InvalidLength { length: 0 }
+    );
+
+    assert_eq!(
+        Eui48::try_from("4d7e54972e").err().unwrap(),
+        StringToEuiError::InvalidLength { length: 10 }
+    );
+
+    assert_eq!(
+        Eui48::try_from("4d7e54972eefef4d").err().unwrap(),
+        StringToEuiError::InvalidLength { length: 16 }
+    );
+
+    assert_eq!(
+        Eui48::try_from("4d7e54972eefef4da").err().unwrap(),
+        StringToEuiError::InvalidLength { length: 17 }
+    );
+}
+
+#[test]
+fn test_eui64_try_from_invalid_length() {
+    assert_eq!(
+        Eui64::try_from("").err().unwrap(),
+        StringToEuiError::InvalidLength { length: 0 }
+    );
+
+    assert_eq!(
+        Eui64::try_from("4d7e54972eaa").err().unwrap(),
+        StringToEuiError::InvalidLength { length: 12 }
+    );
+
+    assert_eq!(
+        Eui64::try_from("4d7e54972eefef4ddd").err().unwrap(),
+        StringToEuiError::InvalidLength { length: 18 }
+    );
+}
+
+#[test]
+fn test_eui48_try_from_invalid_character() {
+    assert_eq!(
+        Eui48::try_from("ad7e54972eja").err().unwrap(),
+        StringToEuiError::InvalidChar {
+            char: 'j',
+            position: 10
+        }
+    );
+}
+
+#[test]
+fn test_eui64_try_from_invalid_character() {
+    assert_eq!(
+        Eui64::try_from("ad7e54972ea721sa").err().unwrap(),
+        StringToEuiError::InvalidChar {
+            char: 's',
+            position: 14
+        }
+    );
+}
+
+#[test]
+fn test_eui48_try_from_invalid_separator_position() {
+    assert_eq!(
+        Eui48::try_from(":4d7e:54:97:2e:ef").err().unwrap(),
+        StringToEuiError::InvalidSeparatorPlace { position: 0 }
+    );
+
+    assert_eq!(
+        Eui48::try_from("4d:7e:54:97:2eef:").err().unwrap(),
+        StringToEuiError::InvalidSeparatorPlace { position: 14 }
+    );
+
+    assert_eq!(
+        Eui48::try_from("4d::7e54:97:2e:ef").err().unwrap(),
+        StringToEuiError::InvalidSeparatorPlace { position: 3 }
+    );
+}
+
+#[test]
+fn test_eui64_try_from_invalid_separator_position() {
+    assert_eq!(
+        Eui64::try_from(":4d7e:54:00:00:97:2e:ef").err().unwrap(),
+        StringToEuiError::InvalidSeparatorPlace { position: 0 }
+    );
+
+    assert_eq!(
+        Eui64::try_from("4d:7e:54:00:00:97:2eef:").err().unwrap(),
+        StringToEuiError::InvalidSeparatorPlace { position: 20 }
+    );
+
+    assert_eq!(
+        Eui64::try_from("4d::7e54:00:00:97:2e:ef").err().unwrap(),
+        StringToEuiError::InvalidSeparatorPlace { position: 3 }
+    );
+}
+
+#[test]
+fn test_eui48_try_from_string_different_separators() {
+    assert_eq!(
+        Eui48::try_from("4d:7e:54-97:2e:ef").err().unwrap(),
+        StringToEuiError::OnlyOneSeparatorTypeExpected { position: 8 }
+    );
+}
+
+#[test]
+fn test_eui64_try_from_string_different_separators() {
+    assert_eq!(
+        Eui64::try_from("4d:7e-54:00:00:97:2e-ef").err().unwrap(),
+        StringToEuiError::OnlyOneSeparatorTypeExpected { position: 5 }
+    );
+}
+
+#[test]
+fn test_eui48_short_id() {
+    let eui48 = Eui48::from(85204980412143);
+
+    assert_eq!(eui48.short_id(3), "972eef");
+    assert_eq!(eui48.short_id(0), "");
+    assert_eq!(eui48.short_id(10), "4d7e54972eef");
+}
+
+#[test]
+fn test_eui64_short_id() {
+    let eui64 = Eui64::from(5583992946972634863);
+
+    assert_eq!(eui64.short_id(2), "2eef");
+    assert_eq!(eui64.short_id(8), "4d7e540000972eef");
+}
+
+#[test]
+fn test_eui48_prefixed_short_id() {
+    let eui48 = Eui48::from(85204980412143);
+
+    let hostname: String<32> = eui48.prefixed_short_id("sensor", 3).unwrap();
+    assert_eq!(hostname, "sensor-972eef");
+
+    assert_eq!(eui48.prefixed_short_id::<8>("sensor", 3), None);
+}
+
+#[test]
+fn test_eui64_prefixed_short_id() {
+    let eui64 = Eui64::from(5583992946972634863);
+
+    let name: String<16> = eui64.prefixed_short_id("node", 4).unwrap();
+    assert_eq!(name, "node-00972eef");
+}
+
+#[test]
+fn test_eui48_ieee_canonical() {
+    let eui48 = Eui48::from(85204980412143);
+
+    assert_eq!(eui48.to_ieee_canonical(), "4D-7E-54-97-2E-EF");
+    assert_eq!(
+        Eui48::from_ieee_canonical("4D-7E-54-97-2E-EF").unwrap(),
+        eui48
+    );
+}
+
+#[test]
+fn test_eui64_ieee_canonical() {
+    let eui64 = Eui64::from(5583992946972634863);
+
+    assert_eq!(eui64.to_ieee_canonical(), "4D-7E-54-00-00-97-2E-EF");
+    assert_eq!(
+        Eui64::from_ieee_canonical("4D-7E-54-00-00-97-2E-EF").unwrap(),
+        eui64
+    );
+}
+
+#[test]
+fn test_eui48_from_ieee_canonical_rejects_other_forms() {
+    assert_eq!(
+        Eui48::from_ieee_canonical("4D7E54972EEF").err().unwrap(),
+        StringToEuiError::InvalidLength { length: 12 }
+    );
+
+    assert_eq!(
+        Eui48::from_ieee_canonical("4d-7e-54-97-2e-ef")
+            .err()
+            .unwrap(),
+        StringToEuiError::InvalidChar {
+            char: 'd',
+            position: 1
+        }
+    );
+
+    assert_eq!(
+        Eui48::from_ieee_canonical("4D:7E:54:97:2E:EF")
+            .err()
+            .unwrap(),
+        StringToEuiError::InvalidChar {
+            char: ':',
+            position: 2
+        }
+    );
+
+    assert_eq!(
+        Eui48::from_ieee_canonical("4D-7E5-4-97-2E-EF")
+            .err()
+            .unwrap(),
+        StringToEuiError::InvalidChar {
+            char: '5',
+            position: 5
+        }
+    );
+}
+
+#[test]
+fn test_eui64_from_ieee_canonical_rejects_other_forms() {
+    assert_eq!(
+        Eui64::from_ieee_canonical("4D-7E-54-00-00-97-2E-ef")
+            .err()
+            .unwrap(),
+        StringToEuiError::InvalidChar {
+            char: 'e',
+            position: 21
+        }
+    );
+
+    assert_eq!(
+        Eui64::from_ieee_canonical("4D-7E-54--0-00-97-2E-EF")
+            .err()
+            .unwrap(),
+        StringToEuiError::InvalidSeparatorPlace { position: 9 }
+    );
+}
+
+#[test]
+fn test_eui48_oui_and_nic_u32() {
+    let eui48 = Eui48::from(85204980412143);
+
+    assert_eq!(eui48.oui_u32(), 0x4D7E54);
+    assert_eq!(eui48.nic_u32(), 0x972EEF);
+}
+
+#[test]
+fn test_eui64_oui_u32() {
+    let eui64 = Eui64::from(5583992946972634863);
+
+    assert_eq!(eui64.oui_u32(), 0x4D7E54);
+}
+
+#[test]
+fn test_eui48_tuple_conversions() {
+    let eui48 = Eui48::from((0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef));
+
+    assert_eq!(u64::from(eui48), 85204980412143);
+    assert_eq!(
+        <(u8, u8, u8, u8, u8, u8)>::from(eui48),
+        (0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef)
+    );
+}
+
+#[test]
+fn test_eui64_tuple_conversions() {
+    let eui64 = Eui64::from((0x4d, 0x7e, 0x54, 0x00, 0x00, 0x97, 0x2e, 0xef));
+
+    assert_eq!(u64::from(eui64), 5583992946972634863);
+    assert_eq!(
+        <(u8, u8, u8, u8, u8, u8, u8, u8)>::from(eui64),
+        (0x4d, 0x7e, 0x54, 0x00, 0x00, 0x97, 0x2e, 0xef)
+    );
+}
+
+#[test]
+fn test_link_layer_addr_try_from_string() {
+    assert_eq!(
+        LinkLayerAddr::try_from("4D:7E:54:97:2E:EF").unwrap(),
+        LinkLayerAddr::Eui48(Eui48::from(85204980412143))
+    );
+
+    assert_eq!(
+        LinkLayerAddr::try_from("4D7E540000972EEF").unwrap(),
+        LinkLayerAddr::Eui64(Eui64::from(5583992946972634863))
+    );
+
+    assert_eq!(
+        LinkLayerAddr::try_from("4D7E5400").err().unwrap(),
+        StringToEuiError::InvalidLength { length: 8 }
+    );
+}
+
+#[test]
+fn test_display_link_layer_addr() {
+    extern crate std;
+    use std::format;
+
+    let eui48 = LinkLayerAddr::from(Eui48::from(85204980412143));
+    let eui64 = LinkLayerAddr::from(Eui64::from(5583992946972634863));
+
+    assert_eq!(format!("{}", eui48), "4D-7E-54-97-2E-EF");
+    assert_eq!(format!("{}", eui64), "4D-7E-54-00-00-97-2E-EF");
+}
+
+#[test]
+fn test_eui48_checked_add_in_oui() {
+    let eui48 = Eui48::from(0x4D7E54FFFFFE);
+
+    assert_eq!(eui48.checked_next_in_oui(), Ok(Eui48::from(0x4D7E54FFFFFF)));
+    assert_eq!(eui48.checked_add_in_oui(2), Err(NicOverflowError));
+    assert_eq!(eui48.checked_add_in_oui(u64::MAX), Err(NicOverflowError));
+}
+
+#[test]
+fn test_eui64_checked_add_in_oui() {
+    let eui64 = Eui64::from(0x4D7E54FFFFFFFF00);
+
+    assert_eq!(
+        eui64.checked_add_in_oui(0xff),
+        Ok(Eui64::from(0x4D7E54FFFFFFFFFF))
+    );
+    assert_eq!(eui64.checked_add_in_oui(0x100), Err(NicOverflowError));
+}
+
+#[test]
+fn test_eui48_bucket() {
+    let eui48 = Eui48::from(85204980412143);
+
+    assert_eq!(eui48.bucket(1), 0);
+    assert_eq!(eui48.bucket(16), 11);
+    assert_eq!(eui48.bucket(1000), 295);
+}
+
+#[test]
+fn test_eui64_bucket() {
+    let eui64 = Eui64::from(5583992946972634863);
+
+    assert_eq!(eui64.bucket(1), 0);
+    assert_eq!(eui64.bucket(16), 7);
+    assert_eq!(eui64.bucket(1000), 869);
+}
+
+#[test]
+fn test_bucket_is_consistent() {
+    for value in 0..1000u64 {
+        let eui48 = Eui48::from(value * 7919);
+        let before = eui48.bucket(10);
+        let after = eui48.bucket(11);
+
+        assert!(after == before || after == 10);
+    }
+}
+
+#[test]
+fn test_eui48_from_str() {
+    let eui48: Eui48 = "4d:7e:54:97:2e:ef".parse().unwrap();
+
+    assert_eq!(u64::from(eui48), 85204980412143);
+    assert_eq!(
+        "4d:7e:54:97:2e:eg".parse::<Eui48>(),
+        Err(ParseEuiError::InvalidChar {
+            char: 'g',
+            position: 16
+        })
+    );
+}
+
+#[test]
+fn test_eui64_from_str() {
+    let eui64: Eui64 = "4D-7E-54-00-00-97-2E-EF".parse().unwrap();
+
+    assert_eq!(u64::from(eui64), 5583992946972634863);
+    assert_eq!(
+        "4D-7E-54".parse::<Eui64>(),
+        Err(ParseEuiError::InvalidLength { length: 8 })
+    );
+}
+
+#[test]
+fn test_link_layer_addr_from_str() {
+    assert_eq!(
+        "4d7e54972eef".parse::<LinkLayerAddr>(),
+        Ok(LinkLayerAddr::Eui48(Eui48::from(85204980412143)))
+    );
+}
+
+#[test]
+fn test_eui48_format() {
+    let eui48 = Eui48::from(85204980412143);
+
+    assert_eq!(
+        eui48.format(EuiFormat::Colon, Case::Lower),
+        "4d:7e:54:97:2e:ef"
+    );
+    assert_eq!(
+        eui48.format(EuiFormat::Hyphen, Case::Upper),
+        "4D-7E-54-97-2E-EF"
+    );
+    assert_eq!(
+        eui48.format(EuiFormat::CiscoDot, Case::Lower),
+        "4d7e.5497.2eef"
+    );
+    assert_eq!(eui48.format(EuiFormat::Bare, Case::Upper), "4D7E54972EEF");
+}
+
+#[test]
+fn test_eui64_format() {
+    let eui64 = Eui64::from(5583992946972634863);
+
+    assert_eq!(
+        eui64.format(EuiFormat::Colon, Case::Upper),
+        "4D:7E:54:00:00:97:2E:EF"
+    );
+    assert_eq!(
+        eui64.format(EuiFormat::CiscoDot, Case::Upper),
+        "4D7E.5400.0097.2EEF"
+    );
+    assert_eq!(
+        eui64.format(EuiFormat::Bare, Case::Lower),
+        "4d7e540000972eef"
+    );
+}
+
+#[test]
+fn test_display_flags_eui48() {
+    extern crate std;
+    use std::format;
+
+    let eui48 = Eui48::from(85204980412143);
+
+    assert_eq!(format!("{:-}", eui48), "4d-7e-54-97-2e-ef");
+    assert_eq!(format!("{:#}", eui48), "4d:7e:54:97:2e:ef");
+    assert_eq!(format!("{:+}", eui48), "4d7e.5497.2eef");
+}
+
+#[test]
+fn test_display_flags_eui64() {
+    extern crate std;
+    use std::format;
+
+    let eui64 = Eui64::from(5583992946972634863);
 
-        Ok(Eui48(result))
-    }
+    assert_eq!(format!("{:-}", eui64), "4d-7e-54-00-00-97-2e-ef");
+    assert_eq!(format!("{:#}", eui64), "4d:7e:54:00:00:97:2e:ef");
+    assert_eq!(format!("{:+}", eui64), "4d7e.5400.0097.2eef");
 }
 
-impl TryFrom<&str> for Eui64 {
-    type Error = StringToEuiError;
+#[test]
+fn test_eui48_bytes() {
+    let bytes = [0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef];
+    let eui48 = Eui48::from_bytes(bytes);
 
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if value.len() != 16 && value.len() != 23 {
-            return Err(StringToEuiError::InvalidLength {
-                length: value.len(),
-            });
-        }
+    assert_eq!(u64::from(eui48), 85204980412143);
+    assert_eq!(eui48.as_bytes(), &bytes);
+    assert_eq!(eui48.into_bytes(), bytes);
+    assert_eq!(Eui48::from(bytes), eui48);
+    assert_eq!(<[u8; 6]>::from(eui48), bytes);
+}
 
-        let mut result = [0; 8];
-        string_to_eui(value, &mut result[..])?;
+#[test]
+fn test_eui64_bytes() {
+    let bytes = [0x4d, 0x7e, 0x54, 0x00, 0x00, 0x97, 0x2e, 0xef];
+    let eui64 = Eui64::from_bytes(bytes);
 
-        Ok(Eui64(result))
-    }
+    assert_eq!(u64::from(eui64), 5583992946972634863);
+    assert_eq!(eui64.as_bytes(), &bytes);
+    assert_eq!(eui64.into_bytes(), bytes);
+    assert_eq!(Eui64::from(bytes), eui64);
+    assert_eq!(<[u8; 8]>::from(eui64), bytes);
 }
 
-impl From<Eui48> for Eui64 {
-    fn from(eui48: Eui48) -> Self {
-        let mut data = [0u8; 8];
+#[test]
+fn test_eui48_try_from_slice() {
+    let packet = [0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef, 0x08, 0x00];
 
-        for i in 0..3 {
-            data[i] = eui48.0[i]
-        }
+    assert_eq!(
+        Eui48::try_from(&packet[..6]).unwrap(),
+        Eui48::from(85204980412143)
+    );
+    assert!(Eui48::try_from(&packet[..]).is_err());
+}
 
-        for i in 5..8 {
-            data[i] = eui48.0[i - 2]
-        }
+#[test]
+fn test_eui64_try_from_slice() {
+    let packet = [0x4d, 0x7e, 0x54, 0x00, 0x00, 0x97, 0x2e, 0xef];
 
-        Eui64(data)
-    }
+    assert_eq!(
+        Eui64::try_from(&packet[..]).unwrap(),
+        Eui64::from(5583992946972634863)
+    );
+    assert!(Eui64::try_from(&packet[..6]).is_err());
 }
 
-impl From<Eui48> for u64 {
-    fn from(eui48: Eui48) -> Self {
-        let data = eui48.0;
+#[test]
+fn test_eui48_to_modified_eui64() {
+    let eui48 = Eui48::from(85204980412143);
 
-        ((data[0] as u64) << 40)
-            + ((data[1] as u64) << 32)
-            + ((data[2] as u64) << 24)
-            + ((data[3] as u64) << 16)
-            + ((data[4] as u64) << 8)
-            + ((data[5] as u64) << 0)
-    }
+    assert_eq!(
+        eui48.to_modified_eui64().to_string(),
+        "4F-7E-54-FF-FE-97-2E-EF"
+    );
+    assert_eq!(
+        eui48.to_eui64_zero_padded().to_string(),
+        "4D-7E-54-00-00-97-2E-EF"
+    );
 }
 
-impl From<Eui64> for u64 {
-    fn from(eui64: Eui64) -> Self {
-        u64::from_be_bytes(eui64.0)
-    }
-}
+#[test]
+fn test_eui64_to_ipv6_interface_id() {
+    let eui64 = Eui64::from(0x0012_4B00_0102_0304);
 
-impl Display for Eui48 {
-    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
-        write!(f, "{}", self.to_string())
-    }
+    assert_eq!(
+        eui64.to_ipv6_interface_id(),
+        [0x02, 0x12, 0x4b, 0x00, 0x01, 0x02, 0x03, 0x04]
+    );
+    assert_eq!(
+        eui64.to_link_local_parts(),
+        [0xfe80, 0, 0, 0, 0x0212, 0x4b00, 0x0102, 0x0304]
+    );
 }
 
-impl Display for Eui64 {
-    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
-        write!(f, "{}", self.to_string())
-    }
-}
+#[test]
+fn test_eui48_to_modified_eui64_local_address() {
+    let eui48 = Eui48::from(0x0200_5E10_0000);
 
-impl UpperHex for Eui48 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:X}", u64::from(*self))
-    }
+    assert_eq!(
+        eui48.to_modified_eui64().as_bytes(),
+        &[0x00, 0x00, 0x5e, 0xff, 0xfe, 0x10, 0x00, 0x00]
+    );
 }
 
-impl LowerHex for Eui48 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:x}", u64::from(*self))
-    }
+#[test]
+fn test_eui48_try_from_eui64() {
+    let eui48 = Eui48::from(85204980412143);
+
+    assert_eq!(Eui48::try_from(Eui64::from(eui48)), Ok(eui48));
+    assert_eq!(Eui48::try_from(Eui64::from(0x4D7E54FFFE972EEF)), Ok(eui48));
+    assert_eq!(
+        Eui48::try_from(Eui64::from(0x4D7E54FFFF972EEF)),
+        Err(Eui64ToEui48Error)
+    );
 }
 
-impl UpperHex for Eui64 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:X}", u64::from(*self))
-    }
+#[test]
+fn test_eui48_classification() {
+    let eui48 = Eui48::from(0x4D7E54972EEF);
+
+    assert!(eui48.is_multicast() && !eui48.is_unicast());
+    assert!(eui48.is_universal() && !eui48.is_local());
+
+    let eui48 = Eui48::from(0x4E7E54972EEF);
+
+    assert!(eui48.is_unicast() && !eui48.is_multicast());
+    assert!(eui48.is_local() && !eui48.is_universal());
 }
 
-impl LowerHex for Eui64 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:x}", u64::from(*self))
-    }
+#[test]
+fn test_eui48_broadcast_and_nil() {
+    assert!(Eui48::BROADCAST.is_broadcast());
+    assert!(Eui48::BROADCAST.is_multicast());
+    assert!(Eui48::NIL.is_nil());
+    assert!(Eui48::NIL.is_unicast());
+    assert_eq!(Eui48::BROADCAST.to_string(), "FF-FF-FF-FF-FF-FF");
+    assert!(!Eui48::from(1).is_nil());
 }
 
 #[test]
-fn test_eui48_to_string() {
-    let eui48 = Eui48::from(85204980412143);
+fn test_eui64_classification() {
+    let eui64 = Eui64::from(0x0012_4B00_0102_0304);
 
-    assert_eq!(eui48.to_string(), "4D-7E-54-97-2E-EF")
+    assert!(eui64.is_unicast() && eui64.is_universal());
+    assert!(!eui64.is_broadcast() && !eui64.is_nil());
+    assert!(Eui64::BROADCAST.is_broadcast());
+    assert!(Eui64::NIL.is_nil());
 }
 
 #[test]
-fn test_eui64_to_string() {
-    let eui64 = Eui64::from(5583992946972634863);
+fn test_eui48_universal_local_bit() {
+    let mut eui48 = Eui48::from(0x4C7E54972EEF);
 
-    assert_eq!(eui64.to_string(), "4D-7E-54-00-00-97-2E-EF")
+    eui48.set_local(true);
+    assert_eq!(eui48, Eui48::from(0x4E7E54972EEF));
+
+    eui48.toggle_universal_local();
+    assert_eq!(eui48, Eui48::from(0x4C7E54972EEF));
+
+    assert!(eui48.with_local().is_local());
+    assert!(eui48.with_local().with_universal().is_universal());
 }
 
 #[test]
-fn test_eui48_to_eui64() {
-    let eui48 = Eui48::from(85204980412143);
-    let eui64 = Eui64::from(eui48);
+fn test_eui48_unicast_multicast_bit() {
+    let mut eui48 = Eui48::from(0x4C7E54972EEF);
 
-    assert_eq!(eui64.to_string(), "4D-7E-54-00-00-97-2E-EF")
+    eui48.set_multicast(true);
+    assert_eq!(eui48, Eui48::from(0x4D7E54972EEF));
+
+    eui48.toggle_unicast_multicast();
+    assert_eq!(eui48, Eui48::from(0x4C7E54972EEF));
+
+    assert!(eui48.with_multicast().is_multicast());
+    assert!(eui48.with_multicast().with_unicast().is_unicast());
 }
 
 #[test]
-fn test_u64_from_eui48() {
-    let eui48 = Eui48::from(85204980412143);
-    assert_eq!(u64::from(eui48), 85204980412143);
+fn test_eui64_bit_manipulation() {
+    let eui64 = Eui64::from(0x0012_4B00_0102_0304);
+
+    assert_eq!(eui64.with_local(), Eui64::from(0x0212_4B00_0102_0304));
+    assert_eq!(eui64.with_multicast(), Eui64::from(0x0112_4B00_0102_0304));
+
+    let mut eui64 = eui64.with_local();
+    eui64.toggle_universal_local();
+    assert!(eui64.is_universal());
 }
 
 #[test]
-fn test_u64_from_eui64() {
-    let eui64 = Eui64::from(5583992946972634863);
-    assert_eq!(u64::from(eui64), 5583992946972634863);
+fn test_eui48_ordering() {
+    let low = Eui48::from(0x00FF_FFFF_FFFF);
+    let high = Eui48::from(0x0100_0000_0000);
+
+    assert!(low < high);
+    assert_eq!(low.cmp(&high), Ordering::Less);
+    assert_eq!(low.compare(&high), Ordering::Less);
+    assert_eq!(high.compare(&low), Ordering::Greater);
+    assert_eq!(high.compare(&high), Ordering::Equal);
 }
 
 #[test]
-fn test_hash_eui48() {
-    use heapless::FnvIndexMap;
-
-    let eui48 = Eui48::from(85204980412143);
+fn test_eui64_ordering() {
+    let mut table = [Eui64::from(3), Eui64::from(1 << 40), Eui64::from(2)];
+    table.sort();
 
-    let mut fnv_index_map: FnvIndexMap<Eui48, u8, U1> = FnvIndexMap::new();
-    fnv_index_map.insert(eui48, 1).unwrap();
-
-    assert_eq!(1, *fnv_index_map.get(&eui48).unwrap())
+    assert_eq!(
+        table,
+        [Eui64::from(2), Eui64::from(3), Eui64::from(1 << 40)]
+    );
+    assert_eq!(table.binary_search(&Eui64::from(3)), Ok(1));
+    assert_eq!(table[1].compare(&table[2]), Ordering::Less);
 }
 
 #[test]
-fn test_hash_eui64() {
-    use heapless::FnvIndexMap;
+fn test_const_compare() {
+    const TABLE: [Eui48; 2] = [Eui([0, 0, 0, 0, 0, 1]), Eui([0, 0, 0, 0, 1, 0])];
+    const _: () = assert!(matches!(TABLE[0].compare(&TABLE[1]), Ordering::Less));
 
-    let eui64 = Eui64::from(5583992946972634863);
+    assert_eq!(TABLE[1].compare(&TABLE[0]), Ordering::Greater);
+}
 
-    let mut fnv_index_map: FnvIndexMap<Eui64, u8, U1> = FnvIndexMap::new();
-    fnv_index_map.insert(eui64, 1).unwrap();
+#[test]
+fn test_eui48_macro() {
+    const TABLE: [Eui48; 2] = [eui48!("4d:7e:54:97:2e:ef"), eui48!("4D7E54972EF0")];
 
-    assert_eq!(1, *fnv_index_map.get(&eui64).unwrap())
+    assert_eq!(TABLE[0], Eui48::from(85204980412143));
+    assert_eq!(TABLE[1], Eui48::from(85204980412144));
 }
 
 #[test]
-fn test_display_eui48() {
-    extern crate std;
-    use std::format;
-
-    let eui48 = Eui48::from(85204980412143);
+fn test_eui64_macro() {
+    const EUI64: Eui64 = eui64!("4D-7E-54-00-00-97-2E-EF");
 
-    assert_eq!(format!("{}", eui48), "4D-7E-54-97-2E-EF");
+    assert_eq!(EUI64, Eui64::from(5583992946972634863));
 }
 
 #[test]
-fn test_display_eui64() {
-    extern crate std;
-    use std::format;
-
-    let eui64 = Eui64::from(5583992946972634863);
+fn test_const_constructors() {
+    const EUI48: Eui48 = Eui48::new(85204980412143);
+    const EUI64: Eui64 = EUI48.to_eui64_zero_padded();
+    const OUI: Oui = EUI48.oui();
+    const _: () = assert!(EUI48.is_multicast() && EUI48.is_universal() && !EUI48.is_broadcast());
+    const _: () = assert!(Eui48::NIL.is_nil() && Eui64::BROADCAST.is_broadcast());
+
+    assert_eq!(EUI48, Eui48::from(85204980412143));
+    assert_eq!(EUI64, Eui64::from(5583992946972634863));
+    assert_eq!(OUI, Oui::from(0x4D7E54));
+    assert_eq!(Eui48::new(u64::MAX), Eui48::BROADCAST);
+}
 
-    assert_eq!(format!("{}", eui64), "4D-7E-54-00-00-97-2E-EF");
+#[test]
+fn test_parse_str_non_ascii() {
+    assert_eq!(
+        Eui48::parse_str("4d:7e:54:97:2e:é"),
+        Err(StringToEuiError::InvalidChar {
+            char: 'é',
+            position: 15
+        })
+    );
+    assert_eq!(
+        Eui48::parse_str("4d7e54972eŁ"),
+        Err(StringToEuiError::InvalidChar {
+            char: 'Ł',
+            position: 10
+        })
+    );
 }
 
 #[test]
-fn test_format_upper_hex_eui48() {
-    extern crate std;
-    use std::format;
+fn test_parse_ascii() {
+    assert_eq!(
+        Eui48::parse_ascii(b"4d:7e:54:97:2e:ef"),
+        Ok(Eui48::from(85204980412143))
+    );
+    assert_eq!(
+        Eui64::parse_ascii(b"4d7e.5400.0097.2eef"),
+        Ok(Eui64::from(5583992946972634863))
+    );
+    assert_eq!(
+        Eui48::parse_ascii(b"4d7e54972e\xff\xfe"),
+        Err(ParseEuiError::InvalidChar {
+            char: char::REPLACEMENT_CHARACTER,
+            position: 10
+        })
+    );
+    assert_eq!(
+        Eui48::parse_ascii(b"4d7e54972e\xc3"),
+        Err(ParseEuiError::InvalidLength { length: 11 })
+    );
+}
 
+#[test]
+fn test_eui48_try_from_cisco_and_spaces() {
     let eui48 = Eui48::from(85204980412143);
 
-    assert_eq!(format!("{:X}", eui48), "4D7E54972EEF");
+    assert_eq!(Eui48::try_from("4d7e.5497.2eef"), Ok(eui48));
+    assert_eq!(Eui48::try_from("4D 7E 54 97 2E EF"), Ok(eui48));
+    assert_eq!(
+        eui48.format(EuiFormat::CiscoDot, Case::Lower).parse(),
+        Ok(eui48)
+    );
 }
 
 #[test]
-fn test_format_upper_hex_eui64() {
-    extern crate std;
-    use std::format;
-
+fn test_eui64_try_from_cisco_and_spaces() {
     let eui64 = Eui64::from(5583992946972634863);
 
-    assert_eq!(format!("{:X}", eui64), "4D7E540000972EEF");
+    assert_eq!(Eui64::try_from("4d7e.5400.0097.2eef"), Ok(eui64));
+    assert_eq!(Eui64::try_from("4d 7e 54 00 00 97 2e ef"), Ok(eui64));
+    assert_eq!(
+        LinkLayerAddr::try_from("4d7e.5400.0097.2eef"),
+        Ok(LinkLayerAddr::Eui64(eui64))
+    );
 }
 
 #[test]
-fn test_format_lower_hex_eui48() {
-    extern crate std;
-    use std::format;
+fn test_try_from_invalid_cisco() {
+    assert_eq!(
+        Eui48::try_from("4d7e5.497.2eef"),
+        Err(StringToEuiError::InvalidSeparatorPlace { position: 5 })
+    );
+    assert_eq!(
+        Eui48::try_from("4d:7e 54:97:2e:ef"),
+        Err(StringToEuiError::OnlyOneSeparatorTypeExpected { position: 5 })
+    );
+    assert_eq!(
+        Eui48::try_from("4d:7e:54972eef"),
+        Err(StringToEuiError::InvalidSeparatorPlace { position: 8 })
+    );
+    assert_eq!(
+        Eui64::try_from("4d7e.5400.0097.2ee"),
+        Err(StringToEuiError::InvalidLength { length: 18 })
+    );
+}
 
-    let eui48 = Eui48::from(85204980412143);
+#[test]
+fn test_parse_error_code_and_position() {
+    let error = Eui48::try_from("4d:7e:54:97:2e:eg").unwrap_err();
 
-    assert_eq!(format!("{:x}", eui48), "4d7e54972eef");
+    assert_eq!(error.code(), 2);
+    assert_eq!(error.position(), Some(16));
+
+    let error = Eui48::try_from("4d7e54972e").unwrap_err();
+
+    assert_eq!(error.code(), 1);
+    assert_eq!(error.position(), None);
 }
 
 #[test]
-fn test_format_lower_hex_eui64() {
+fn test_anonymized_and_redacted() {
     extern crate std;
     use std::format;
 
+    let eui48 = Eui48::from(85204980412143);
     let eui64 = Eui64::from(5583992946972634863);
 
-    assert_eq!(format!("{:x}", eui64), "4d7e540000972eef");
+    assert_eq!(eui48.anonymized(), Eui48::from(0x4D7E54000000));
+    assert_eq!(eui64.anonymized(), Eui64::from(0x4D7E540000000000));
+    assert_eq!(format!("{}", eui48.redacted()), "4d:7e:54:xx:xx:xx");
+    assert_eq!(format!("{}", eui64.redacted()), "4d:7e:54:xx:xx:xx:xx:xx");
 }
 
 #[test]
-fn test_eui48_try_from_string() {
-    let eui48 = Eui48::try_from("4D7E54972EEF").unwrap();
+fn test_eui48_hash_crc32() {
+    let eui48 = Eui48::from(85204980412143);
 
-    assert_eq!(u64::from(eui48), 85204980412143);
+    assert_eq!(eui48.hash_crc32(), 0xC36726FA);
+    assert_eq!(Eui48::BROADCAST.hash_crc32(), 0x41D9ED00);
+    assert_eq!(eui48.hash_to_bucket(64), 40);
+    assert_eq!(eui48.hash_to_bucket(256), 160);
+    assert_eq!(Eui48::from(0x01005E000001).hash_to_bucket(64), 31);
+    assert_eq!(eui48.hash_to_bucket(1), 0);
 }
 
 #[test]
-fn test_eui64_try_from_string() {
-    let eui64 = Eui64::try_from("4D7E540000972EEF").unwrap();
-
-    assert_eq!(u64::from(eui64), 5583992946972634863);
+#[should_panic]
+fn test_eui48_hash_to_bucket_not_power_of_two() {
+    Eui48::from(85204980412143).hash_to_bucket(48);
 }
 
+#[cfg(feature = "std")]
 #[test]
-fn test_eui48_try_from_string_with_separator() {
-    let eui48_1 = Eui48::try_from("4D-7E-54-97-2E-EF").unwrap();
-    let eui48_2 = Eui48::try_from("4D:7E:54:97:2E:EF").unwrap();
+fn test_std_hash_set() {
+    use std::collections::HashSet;
 
-    assert_eq!(u64::from(eui48_1), 85204980412143);
-    assert_eq!(u64::from(eui48_2), 85204980412143);
+    let mut set = HashSet::new();
+
+    assert!(set.insert(Eui48::from(85204980412143)));
+    assert!(!set.insert(Eui48::from(85204980412143)));
+    assert!(set.insert(Eui48::from(1)));
 }
 
+#[cfg(feature = "std")]
 #[test]
-fn test_eui64_try_from_string_with_separator() {
-    let eui64_1 = Eui64::try_from("4D-7E-54-00-00-97-2E-EF").unwrap();
-    let eui64_2 = Eui64::try_from("4D:7E:54:00:00:97:2E:EF").unwrap();
+fn test_parse_error_is_std_error() {
+    use std::boxed::Box;
+    use std::error::Error;
+    use std::string::ToString;
 
-    assert_eq!(u64::from(eui64_1), 5583992946972634863);
-    assert_eq!(u64::from(eui64_2), 5583992946972634863);
-}
+    let error: Box<dyn Error> = Box::new(Eui48::try_from("4d7e54972e").unwrap_err());
 
-#[test]
-fn test_eui48_try_from_invalid_length() {
+    assert_eq!(error.to_string(), "Invalid length 10");
     assert_eq!(
-        Eui48::try_from("").err().unwrap(),
-        StringToEuiError::InvalidLength { length: 0 }
+        Eui48::try_from("ad7e54972esa").unwrap_err().to_string(),
+        "Invalid character `s` at position 10"
     );
-
     assert_eq!(
-        Eui48::try_from("4d7e54972e").err().unwrap(),
-        StringToEuiError::InvalidLength { length: 10 }
+        Eui48::try_from(Eui64::from(0x4D7E541234972EEF))
+            .unwrap_err()
+            .to_string(),
+        "EUI-64 doesn't encapsulate an EUI-48"
     );
+}
 
-    assert_eq!(
-        Eui48::try_from("4d7e54972eefef4d").err().unwrap(),
-        StringToEuiError::InvalidLength { length: 16 }
-    );
+#[test]
+fn test_eui64_byte_order() {
+    let le = [0xef, 0x2e, 0x97, 0x00, 0x00, 0x54, 0x7e, 0x4d];
+    let eui64 = Eui64::from_le_bytes(le);
+
+    assert_eq!(eui64, Eui64::from(5583992946972634863));
+    assert_eq!(eui64.to_le_bytes(), le);
+    assert_eq!(eui64.to_be_bytes(), *eui64.as_bytes());
+    assert_eq!(Eui64::from_be_bytes(le), eui64.reversed());
+    assert_eq!(eui64.reversed().reversed(), eui64);
+}
 
+#[test]
+fn test_eui48_try_from_u64() {
     assert_eq!(
-        Eui48::try_from("4d7e54972eefef4da").err().unwrap(),
-        StringToEuiError::InvalidLength { length: 17 }
+        Eui48::try_from_u64(85204980412143),
+        Ok(Eui48::from(85204980412143))
     );
+    assert_eq!(Eui48::try_from_u64(0xFFFFFFFFFFFF), Ok(Eui48::BROADCAST));
+    assert_eq!(Eui48::try_from_u64(1 << 48), Err(Eui48RangeError));
+    assert_eq!(Eui48::from_u64_lossy(1 << 48 | 1), Eui48::from(1));
 }
 
+#[cfg(feature = "bytemuck")]
 #[test]
-fn test_eui64_try_from_invalid_length() {
-    assert_eq!(
-        Eui64::try_from("").err().unwrap(),
-        StringToEuiError::InvalidLength { length: 0 }
-    );
+fn test_bytemuck_overlay() {
+    #[derive(Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+    #[repr(C, packed)]
+    struct EthernetHeader {
+        destination: Eui48,
+        source: Eui48,
+        ether_type: [u8; 2],
+    }
 
-    assert_eq!(
-        Eui64::try_from("4d7e54972eaa").err().unwrap(),
-        StringToEuiError::InvalidLength { length: 12 }
-    );
+    let frame = [
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef, 0x08, 0x06,
+    ];
+    let header: &EthernetHeader = bytemuck::from_bytes(&frame);
 
-    assert_eq!(
-        Eui64::try_from("4d7e54972eefef4ddd").err().unwrap(),
-        StringToEuiError::InvalidLength { length: 18 }
-    );
+    assert_eq!({ header.destination }, Eui48::BROADCAST);
+    assert_eq!({ header.source }, Eui48::from(85204980412143));
+    assert_eq!(bytemuck::bytes_of(&Eui64::from(1))[7], 1);
 }
 
+#[cfg(feature = "zerocopy")]
 #[test]
-fn test_eui48_try_from_invalid_character() {
-    assert_eq!(
-        Eui48::try_from("ad7e54972eja").err().unwrap(),
-        StringToEuiError::InvalidChar { char: 'j' }
-    );
+fn test_zerocopy_overlay() {
+    use zerocopy::{FromBytes, IntoBytes};
+
+    let frame = [0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef, 0x08, 0x06];
+    let (eui48, rest) = Eui48::ref_from_prefix(&frame).unwrap();
+
+    assert_eq!(*eui48, Eui48::from(85204980412143));
+    assert_eq!(rest, [0x08, 0x06]);
+    assert_eq!(IntoBytes::as_bytes(&Eui64::from(1))[7], 1);
 }
 
 #[test]
-fn test_eui64_try_from_invalid_character() {
+fn test_generic_eui() {
+    let eui48: Eui<6> = Eui::from([0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef]);
+
+    assert_eq!(eui48, Eui48::from(85204980412143));
+    assert_eq!(Eui::<6>::parse_str("4d7e.5497.2eef"), Ok(eui48));
     assert_eq!(
-        Eui64::try_from("ad7e54972ea721sa").err().unwrap(),
-        StringToEuiError::InvalidChar { char: 's' }
+        Eui::<4>::parse_str("C0-FF-EE-01").unwrap().oui_u32(),
+        0xC0FFEE
+    );
+    assert_eq!(
+        Eui::<10>::parse_lenient("0x0102030405060708090a")
+            .unwrap()
+            .into_bytes(),
+        [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]
     );
+    assert_eq!(!Eui::<3>::NIL, Eui::<3>::BROADCAST);
 }
 
 #[test]
-fn test_eui48_try_from_invalid_separator_position() {
-    assert_eq!(
-        Eui48::try_from(":4d7e:54:97:2e:ef").err().unwrap(),
-        StringToEuiError::InvalidSeparatorPlace
-    );
+fn test_generic_eui_smallest() {
+    let eui = Eui::<3>::parse_str("4D-7E-54").unwrap();
 
+    assert_eq!(Eui::<3>::parse_str("4d7e54"), Ok(eui));
+    assert_eq!(Eui::<3>::parse_str("4d:7e:54"), Ok(eui));
     assert_eq!(
-        Eui48::try_from("4d:7e:54:97:2eef:").err().unwrap(),
-        StringToEuiError::InvalidSeparatorPlace
+        Eui::<3>::parse_str("4d7e5"),
+        Err(ParseEuiError::InvalidLength { length: 5 })
     );
-
+    assert!(eui.is_multicast());
+    assert!(eui.is_universal());
+    assert_eq!(eui.oui_u32(), 0x4D7E54);
+    assert_eq!(eui.anonymized(), eui);
     assert_eq!(
-        Eui48::try_from("4d::7e54:97:2e:ef").err().unwrap(),
-        StringToEuiError::InvalidSeparatorPlace
+        eui.with_local().with_multicast().as_bytes(),
+        &[0x4f, 0x7e, 0x54]
     );
+    assert_eq!(eui.short_id(4), "4d7e54");
 }
 
 #[test]
-fn test_eui64_try_from_invalid_separator_position() {
-    assert_eq!(
-        Eui64::try_from(":4d7e:54:00:00:97:2e:ef").err().unwrap(),
-        StringToEuiError::InvalidSeparatorPlace
-    );
+fn test_generic_eui_longer_than_eui64() {
+    let eui = Eui::<10>::from_bytes([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
 
+    assert_eq!(eui.short_id(10), "0203040506070809");
+    assert_eq!(eui.short_id(2), "0809");
     assert_eq!(
-        Eui64::try_from("4d:7e:54:00:00:97:2eef:").err().unwrap(),
-        StringToEuiError::InvalidSeparatorPlace
+        eui.prefixed_short_id::<32>("node", 10).unwrap(),
+        "node-0203040506070809"
     );
 
+    let mut buf = [0; 29];
+
     assert_eq!(
-        Eui64::try_from("4d::7e54:00:00:97:2e:ef").err().unwrap(),
-        StringToEuiError::InvalidSeparatorPlace
+        eui.write_hex(&mut buf, EuiFormat::Colon),
+        Ok("00:01:02:03:04:05:06:07:08:09")
     );
 }
 
+#[cfg(feature = "std")]
 #[test]
-fn test_eui48_try_from_string_different_separators() {
+fn test_generic_eui_debug() {
     assert_eq!(
-        Eui48::try_from("4d:7e:54-97:2e:ef").err().unwrap(),
-        StringToEuiError::OnlyOneSeparatorTypeExpected
+        std::format!("{:?}", Eui48::from(1)),
+        "Eui48([0, 0, 0, 0, 0, 1])"
     );
-}
-
-#[test]
-fn test_eui64_try_from_string_different_separators() {
+    assert_eq!(std::format!("{:?}", Eui::<3>::NIL), "Eui([0, 0, 0])");
+    assert_eq!(std::format!("{}", Eui::<3>::BROADCAST), "FF-FF-FF");
     assert_eq!(
-        Eui64::try_from("4d:7e-54:00:00:97:2e-ef").err().unwrap(),
-        StringToEuiError::OnlyOneSeparatorTypeExpected
+        std::format!("{}", Eui::<3>::BROADCAST.redacted()),
+        "ff:ff:ff"
     );
 }
@@ -0,0 +1,165 @@
+use crate::format::write_list;
+use crate::{BufferTooSmall, Eui48, Eui64, EuiFormat, ParseEuiError};
+use core::convert::TryFrom;
+
+/// Fills `out` from the addresses in `input` split at `separator`, ignoring
+/// whitespace around each address, error positions are offsets into `input`.
+fn parse_list<'a, T>(input: &'a str, separator: char, out: &mut [T]) -> Result<usize, ParseEuiError>
+where
+    T: TryFrom<&'a str, Error = ParseEuiError>,
+{
+    if input.trim().is_empty() {
+        return Ok(0);
+    }
+
+    let mut count = 0;
+    let mut offset = 0;
+
+    for item in input.split(separator) {
+        let trimmed = item.trim_start();
+        let start = offset + item.len() - trimmed.len();
+
+        offset += item.len() + separator.len_utf8();
+
+        let slot = out
+            .get_mut(count)
+            .ok_or(ParseEuiError::TooManyAddresses { position: start })?;
+
+        *slot = T::try_from(trimmed.trim_end()).map_err(|error| error.offset_by(start))?;
+
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+impl Eui48 {
+    /// Parses a list like `4d:7e:54:97:2e:ef,00:00:5e:00:53:01` into `out`,
+    /// returning the number of addresses.
+    ///
+    /// An empty `input` gives no addresses, an empty entry an
+    /// `InvalidLength` error.
+    pub fn parse_list(
+        input: &str,
+        separator: char,
+        out: &mut [Eui48],
+    ) -> Result<usize, ParseEuiError> {
+        parse_list(input, separator, out)
+    }
+
+    /// Counterpart of [`parse_list`](Eui48::parse_list), writing `addresses`
+    /// into `buf`.
+    pub fn format_list<'a>(
+        addresses: &[Eui48],
+        separator: char,
+        format: EuiFormat,
+        buf: &'a mut [u8],
+    ) -> Result<&'a str, BufferTooSmall> {
+        write_list(
+            buf,
+            addresses.iter().map(|eui48| &eui48.0[..]),
+            separator,
+            format,
+        )
+    }
+}
+
+impl Eui64 {
+    /// Parses a list of addresses into `out`, see
+    /// [`Eui48::parse_list`](Eui48::parse_list).
+    pub fn parse_list(
+        input: &str,
+        separator: char,
+        out: &mut [Eui64],
+    ) -> Result<usize, ParseEuiError> {
+        parse_list(input, separator, out)
+    }
+
+    /// Counterpart of [`parse_list`](Eui64::parse_list), writing `addresses`
+    /// into `buf`.
+    pub fn format_list<'a>(
+        addresses: &[Eui64],
+        separator: char,
+        format: EuiFormat,
+        buf: &'a mut [u8],
+    ) -> Result<&'a str, BufferTooSmall> {
+        write_list(
+            buf,
+            addresses.iter().map(|eui64| &eui64.0[..]),
+            separator,
+            format,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BufferTooSmall, Eui48, Eui64, EuiFormat, ParseEuiError};
+
+    #[test]
+    fn test_eui48_parse_list() {
+        let mut out = [Eui48::from(0); 3];
+
+        assert_eq!(
+            Eui48::parse_list("4d:7e:54:97:2e:ef, 00-00-5E-00-53-01", ',', &mut out),
+            Ok(2)
+        );
+        assert_eq!(out[0], Eui48::from(85204980412143));
+        assert_eq!(out[1], Eui48::from(0x00005E005301));
+        assert_eq!(Eui48::parse_list("", ',', &mut out), Ok(0));
+    }
+
+    #[test]
+    fn test_eui48_parse_list_errors() {
+        let mut out = [Eui48::from(0); 1];
+
+        assert_eq!(
+            Eui48::parse_list("4d7e54972eef;4d7e54972eef", ';', &mut out),
+            Err(ParseEuiError::TooManyAddresses { position: 13 })
+        );
+
+        let mut out = [Eui48::from(0); 2];
+
+        assert_eq!(
+            Eui48::parse_list("4d7e54972eef, 4d7e54972eeg", ',', &mut out),
+            Err(ParseEuiError::InvalidChar {
+                char: 'g',
+                position: 25
+            })
+        );
+        assert_eq!(
+            Eui48::parse_list("4d7e54972eef,", ',', &mut out),
+            Err(ParseEuiError::InvalidLength { length: 0 })
+        );
+    }
+
+    #[test]
+    fn test_eui64_parse_list() {
+        let mut out = [Eui64::from(0); 2];
+
+        assert_eq!(
+            Eui64::parse_list("4d7e.5400.0097.2eef 4d7e540000972eef", ' ', &mut out),
+            Ok(2)
+        );
+        assert_eq!(out, [Eui64::from(5583992946972634863); 2]);
+    }
+
+    #[test]
+    fn test_format_list() {
+        let addresses = [Eui48::from(85204980412143), Eui48::from(0x00005E005301)];
+        let mut buf = [0; 40];
+
+        assert_eq!(
+            Eui48::format_list(&addresses, ',', EuiFormat::Colon, &mut buf),
+            Ok("4d:7e:54:97:2e:ef,00:00:5e:00:53:01")
+        );
+        assert_eq!(
+            Eui48::format_list(&addresses, ',', EuiFormat::Colon, &mut buf[..34]),
+            Err(BufferTooSmall)
+        );
+        assert_eq!(
+            Eui64::format_list(&[], ',', EuiFormat::Colon, &mut buf),
+            Ok("")
+        );
+    }
+}
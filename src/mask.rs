@@ -0,0 +1,215 @@
+use crate::{Eui48, ParseEuiError};
+use core::convert::TryFrom;
+use core::fmt::{Display, Error, Formatter};
+use core::str::FromStr;
+
+/// Bits of an [`Eui48`] taken into account by [`Eui48::matches`]. Set bits
+/// have to match, cleared ones are wildcards.
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Hash)]
+pub struct Eui48Mask(pub(crate) [u8; 6]);
+
+impl Eui48Mask {
+    /// Matches exactly one address.
+    pub const ALL: Eui48Mask = Eui48Mask([0xff; 6]);
+    /// Matches every address.
+    pub const NONE: Eui48Mask = Eui48Mask([0x00; 6]);
+
+    #[inline]
+    pub const fn from_bytes(bytes: [u8; 6]) -> Self {
+        Eui48Mask(bytes)
+    }
+
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8; 6] {
+        &self.0
+    }
+
+    /// Mask of the first `len` bits, e.g. 24 for an OUI. `None` above 48.
+    pub const fn prefix(len: u8) -> Option<Self> {
+        if len > 48 {
+            return None;
+        }
+
+        let value = if len == 0 { 0 } else { u64::MAX << (64 - len) };
+        let [b1, b2, b3, b4, b5, b6, _, _] = value.to_be_bytes();
+
+        Some(Eui48Mask([b1, b2, b3, b4, b5, b6]))
+    }
+
+    /// Length of the prefix if the set bits are contiguous from the start.
+    pub const fn prefix_len(&self) -> Option<u8> {
+        let [b1, b2, b3, b4, b5, b6] = self.0;
+        let value = u64::from_be_bytes([b1, b2, b3, b4, b5, b6, 0, 0]);
+        let len = value.leading_ones();
+
+        if len < 64 && value << len != 0 {
+            return None;
+        }
+
+        Some(len as u8)
+    }
+}
+
+impl Eui48 {
+    /// Whether the address equals `pattern` in all bits set in `mask`.
+    pub const fn matches(&self, pattern: Eui48, mask: Eui48Mask) -> bool {
+        let mut i = 0;
+
+        while i < 6 {
+            if (self.0[i] ^ pattern.0[i]) & mask.0[i] != 0 {
+                return false;
+            }
+
+            i += 1;
+        }
+
+        true
+    }
+}
+
+/// Address with a mask, written as `4d:7e:54:00:00:00/24` for a prefix or as
+/// a plain address to match exactly.
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Hash)]
+pub struct Eui48Pattern {
+    pub address: Eui48,
+    pub mask: Eui48Mask,
+}
+
+impl Eui48Pattern {
+    pub const fn new(address: Eui48, mask: Eui48Mask) -> Self {
+        Eui48Pattern { address, mask }
+    }
+
+    pub const fn matches(&self, eui48: &Eui48) -> bool {
+        eui48.matches(self.address, self.mask)
+    }
+}
+
+/// Possible errors while converting string to [`Eui48Pattern`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParsePatternError {
+    Address(ParseEuiError),
+    /// Prefix length isn't a number from 0 to 48.
+    InvalidPrefixLength,
+}
+
+impl From<ParseEuiError> for ParsePatternError {
+    fn from(error: ParseEuiError) -> Self {
+        ParsePatternError::Address(error)
+    }
+}
+
+impl Display for ParsePatternError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self {
+            ParsePatternError::Address(error) => Display::fmt(error, f),
+            ParsePatternError::InvalidPrefixLength => {
+                f.write_str("Prefix length must be a number from 0 to 48")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParsePatternError {}
+
+impl TryFrom<&str> for Eui48Pattern {
+    type Error = ParsePatternError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut parts = value.splitn(2, '/');
+        let address = Eui48::try_from(parts.next().unwrap_or(""))?;
+
+        let mask = match parts.next() {
+            Some(len) => len
+                .parse()
+                .ok()
+                .and_then(Eui48Mask::prefix)
+                .ok_or(ParsePatternError::InvalidPrefixLength)?,
+            None => Eui48Mask::ALL,
+        };
+
+        Ok(Eui48Pattern { address, mask })
+    }
+}
+
+impl FromStr for Eui48Pattern {
+    type Err = ParsePatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Eui48Pattern::try_from(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui48Mask, Eui48Pattern, ParseEuiError, ParsePatternError};
+
+    #[test]
+    fn test_mask_prefix() {
+        assert_eq!(Eui48Mask::prefix(0), Some(Eui48Mask::NONE));
+        assert_eq!(Eui48Mask::prefix(48), Some(Eui48Mask::ALL));
+        assert_eq!(
+            Eui48Mask::prefix(28),
+            Some(Eui48Mask::from_bytes([0xff, 0xff, 0xff, 0xf0, 0x00, 0x00]))
+        );
+        assert_eq!(Eui48Mask::prefix(49), None);
+    }
+
+    #[test]
+    fn test_mask_prefix_len() {
+        assert_eq!(Eui48Mask::prefix(24).unwrap().prefix_len(), Some(24));
+        assert_eq!(Eui48Mask::ALL.prefix_len(), Some(48));
+        assert_eq!(Eui48Mask::NONE.prefix_len(), Some(0));
+        assert_eq!(
+            Eui48Mask::from_bytes([0xff, 0, 0xff, 0, 0, 0]).prefix_len(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_eui48_matches() {
+        let eui48 = Eui48::from(85204980412143);
+        let oui = Eui48Mask::prefix(24).unwrap();
+
+        assert!(eui48.matches(Eui48::from(0x4D7E54000000), oui));
+        assert!(!eui48.matches(Eui48::from(0x4D7E55000000), oui));
+        assert!(eui48.matches(Eui48::NIL, Eui48Mask::NONE));
+
+        // Only the I/G bit.
+        let group = Eui48Mask::from_bytes([0x01, 0, 0, 0, 0, 0]);
+        assert!(eui48.matches(Eui48::from(0x010000000000), group));
+    }
+
+    #[test]
+    fn test_pattern_from_str() {
+        let pattern: Eui48Pattern = "4d:7e:54:00:00:00/24".parse().unwrap();
+
+        assert_eq!(
+            pattern,
+            Eui48Pattern::new(Eui48::from(0x4D7E54000000), Eui48Mask::prefix(24).unwrap())
+        );
+        assert!(pattern.matches(&Eui48::from(85204980412143)));
+
+        let exact: Eui48Pattern = "4d7e54972eef".parse().unwrap();
+        assert_eq!(exact.mask, Eui48Mask::ALL);
+    }
+
+    #[test]
+    fn test_pattern_from_str_invalid() {
+        assert_eq!(
+            "4d:7e:54:00:00:00/49".parse::<Eui48Pattern>(),
+            Err(ParsePatternError::InvalidPrefixLength)
+        );
+        assert_eq!(
+            "4d:7e:54:00:00:00/".parse::<Eui48Pattern>(),
+            Err(ParsePatternError::InvalidPrefixLength)
+        );
+        assert_eq!(
+            "4d:7e:54:00:00:0/24".parse::<Eui48Pattern>(),
+            Err(ParsePatternError::Address(ParseEuiError::InvalidLength {
+                length: 16
+            }))
+        );
+    }
+}
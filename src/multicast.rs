@@ -0,0 +1,81 @@
+use crate::{Eui, Eui48};
+
+impl Eui48 {
+    /// Ethernet group address of an IPv4 multicast group as of RFC 1112:
+    /// `01-00-5E` followed by the lower 23 bits of `group`. Whether `group` is
+    /// in `224.0.0.0/4` isn't checked.
+    pub const fn from_ipv4_multicast(group: [u8; 4]) -> Self {
+        Eui([0x01, 0x00, 0x5e, group[1] & 0x7f, group[2], group[3]])
+    }
+
+    /// Ethernet group address of an IPv6 multicast group as of RFC 2464:
+    /// `33-33` followed by the last four octets of `group`. Whether `group` is
+    /// in `ff00::/8` isn't checked.
+    pub const fn from_ipv6_multicast(group: [u8; 16]) -> Self {
+        Eui([0x33, 0x33, group[12], group[13], group[14], group[15]])
+    }
+
+    /// Lowest IPv4 group mapping to this address, e.g. for filter setup.
+    ///
+    /// The mapping drops five bits of the group, so `224.1.2.3` and
+    /// `239.129.2.3` share `01-00-5E-01-02-03` and both give back `224.1.2.3`.
+    /// `None` if this isn't an IPv4 multicast address.
+    pub const fn ipv4_multicast_group(&self) -> Option<[u8; 4]> {
+        let b = self.0;
+
+        if b[0] != 0x01 || b[1] != 0x00 || b[2] != 0x5e || b[3] & 0x80 != 0 {
+            return None;
+        }
+
+        Some([224, b[3], b[4], b[5]])
+    }
+
+    /// Last four octets shared by all IPv6 groups mapping to this address.
+    /// `None` if this isn't an IPv6 multicast address.
+    pub const fn ipv6_multicast_suffix(&self) -> Option<[u8; 4]> {
+        let b = self.0;
+
+        if b[0] != 0x33 || b[1] != 0x33 {
+            return None;
+        }
+
+        Some([b[2], b[3], b[4], b[5]])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Eui48;
+
+    #[test]
+    fn test_from_ipv4_multicast() {
+        let eui48 = Eui48::from_ipv4_multicast([224, 0, 0, 251]);
+
+        assert_eq!(eui48, Eui48::from(0x01005E0000FB));
+        assert!(eui48.is_multicast());
+        assert_eq!(
+            Eui48::from_ipv4_multicast([239, 129, 2, 3]),
+            Eui48::from_ipv4_multicast([224, 1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_ipv4_multicast_group() {
+        assert_eq!(
+            Eui48::from_ipv4_multicast([239, 255, 255, 250]).ipv4_multicast_group(),
+            Some([224, 127, 255, 250])
+        );
+        assert_eq!(Eui48::from(0x01005E800001).ipv4_multicast_group(), None);
+        assert_eq!(Eui48::from(85204980412143).ipv4_multicast_group(), None);
+    }
+
+    #[test]
+    fn test_from_ipv6_multicast() {
+        let all_nodes = [0xff, 0x02, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x01];
+        let eui48 = Eui48::from_ipv6_multicast(all_nodes);
+
+        assert_eq!(eui48, Eui48::from(0x333300000001));
+        assert_eq!(eui48.ipv6_multicast_suffix(), Some([0, 0, 0, 0x01]));
+        assert_eq!(Eui48::from(0x01005E0000FB).ipv6_multicast_suffix(), None);
+    }
+}
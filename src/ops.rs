@@ -0,0 +1,83 @@
+//! Bitwise operators applied octet by octet, between two addresses or an
+//! address and a byte array of the same length.
+use crate::Eui;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+macro_rules! impl_bitwise {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt) => {
+        impl<const N: usize> $assign_trait<[u8; N]> for Eui<N> {
+            fn $assign_method(&mut self, rhs: [u8; N]) {
+                for (byte, rhs) in self.0.iter_mut().zip(rhs.iter()) {
+                    *byte $op *rhs;
+                }
+            }
+        }
+
+        impl<const N: usize> $assign_trait for Eui<N> {
+            fn $assign_method(&mut self, rhs: Eui<N>) {
+                $assign_trait::$assign_method(self, rhs.0)
+            }
+        }
+
+        impl<const N: usize> $trait<[u8; N]> for Eui<N> {
+            type Output = Eui<N>;
+
+            fn $method(mut self, rhs: [u8; N]) -> Eui<N> {
+                $assign_trait::$assign_method(&mut self, rhs);
+                self
+            }
+        }
+
+        impl<const N: usize> $trait for Eui<N> {
+            type Output = Eui<N>;
+
+            fn $method(self, rhs: Eui<N>) -> Eui<N> {
+                $trait::$method(self, rhs.0)
+            }
+        }
+    };
+}
+
+impl_bitwise!(BitAnd, bitand, BitAndAssign, bitand_assign, &=);
+impl_bitwise!(BitOr, bitor, BitOrAssign, bitor_assign, |=);
+impl_bitwise!(BitXor, bitxor, BitXorAssign, bitxor_assign, ^=);
+
+impl<const N: usize> Not for Eui<N> {
+    type Output = Eui<N>;
+
+    fn not(mut self) -> Eui<N> {
+        self.0.iter_mut().for_each(|byte| *byte = !*byte);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui64};
+
+    #[test]
+    fn test_eui48_bitwise() {
+        let eui48 = Eui48::from(85204980412143);
+
+        assert_eq!(
+            eui48 & [0xff, 0xff, 0xff, 0, 0, 0],
+            Eui48::from(0x4D7E54000000)
+        );
+        assert_eq!(eui48 | Eui48::from(0x020000000000), eui48.with_local());
+        assert_eq!(eui48 ^ eui48, Eui48::NIL);
+        assert_eq!(!Eui48::NIL, Eui48::BROADCAST);
+    }
+
+    #[test]
+    fn test_eui64_bitwise_assign() {
+        let mut eui64 = Eui64::from(5583992946972634863);
+
+        eui64 ^= [0x02, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(eui64, Eui64::from(0x4F7E540000972EEF));
+
+        eui64 &= Eui64::from(0xFF);
+        eui64 |= [0, 0, 0, 0, 0, 0, 0x01, 0];
+        assert_eq!(eui64, Eui64::from(0x01EF));
+        assert_eq!(!eui64, Eui64::from(0xFFFFFFFFFFFFFE10));
+    }
+}
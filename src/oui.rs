@@ -0,0 +1,340 @@
+use crate::format::{display_format, write_formatted, Case, EuiFormat};
+use crate::vendor::OuiRegistry;
+use crate::{string_to_eui, Eui, Eui48, Eui64, ExtensionRangeError, ParseEuiError};
+use core::convert::TryFrom;
+use core::fmt::{Display, Error, Formatter};
+use core::str::FromStr;
+use heapless::String;
+
+/// Organizationally unique identifier, the 24-bit vendor prefix of an address.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Unaligned,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+#[repr(transparent)]
+pub struct Oui(pub(crate) [u8; 3]);
+
+/// How the IEEE Registration Authority assigned a prefix, see
+/// [`Oui::registry_kind`].
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Hash)]
+#[non_exhaustive]
+pub enum RegistryKind {
+    /// MA-L block of the whole 24-bit OUI.
+    MaL,
+    /// MA-M block, a 28-bit prefix under an OUI of the Registration Authority.
+    MaM,
+    /// MA-S block, a 36-bit prefix under an OUI of the Registration Authority.
+    MaS,
+    /// Company ID, only used for locally administered addresses.
+    Cid,
+    /// Locally administered prefix outside of the CID space.
+    Local,
+    /// Universal prefix whose block size isn't known.
+    Universal,
+}
+
+impl Oui {
+    #[inline]
+    pub const fn from_bytes(bytes: [u8; 3]) -> Self {
+        Oui(bytes)
+    }
+
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8; 3] {
+        &self.0
+    }
+
+    #[inline]
+    pub fn to_string(&self) -> String<8> {
+        self.format(EuiFormat::Hyphen, Case::Upper)
+    }
+
+    pub fn format(&self, format: EuiFormat, case: Case) -> String<8> {
+        let mut string = String::new();
+
+        write_formatted(&mut string, &self.0, format, case).expect("String is not long enough");
+
+        string
+    }
+
+    /// Kind of assignment as far as the bits tell: a CID has the U/L bit set
+    /// and `0xA` in the low nibble of the first octet, other local prefixes are
+    /// [`Local`](RegistryKind::Local) and every universal one
+    /// [`Universal`](RegistryKind::Universal).
+    ///
+    /// Telling MA-L, MA-M and MA-S apart needs the registry, see
+    /// [`registry_kind_in`](Oui::registry_kind_in).
+    pub const fn registry_kind(&self) -> RegistryKind {
+        if self.0[0] & 0x02 == 0 {
+            RegistryKind::Universal
+        } else if self.0[0] & 0x0f == 0x0a {
+            RegistryKind::Cid
+        } else {
+            RegistryKind::Local
+        }
+    }
+
+    /// Same as [`registry_kind`](Oui::registry_kind) with the block size of
+    /// universal prefixes taken from `registry`, if it knows them.
+    pub fn registry_kind_in<R: OuiRegistry + ?Sized>(&self, registry: &R) -> RegistryKind {
+        match self.registry_kind() {
+            RegistryKind::Universal => registry
+                .assignment(*self)
+                .unwrap_or(RegistryKind::Universal),
+            kind => kind,
+        }
+    }
+}
+
+impl From<[u8; 3]> for Oui {
+    fn from(bytes: [u8; 3]) -> Self {
+        Oui(bytes)
+    }
+}
+
+impl From<Oui> for [u8; 3] {
+    fn from(oui: Oui) -> Self {
+        oui.0
+    }
+}
+
+impl From<u32> for Oui {
+    /// Takes the lower 24 bits of `value`.
+    fn from(value: u32) -> Self {
+        let [_, b1, b2, b3] = value.to_be_bytes();
+
+        Oui([b1, b2, b3])
+    }
+}
+
+impl From<Oui> for u32 {
+    fn from(oui: Oui) -> Self {
+        u32::from_be_bytes([0, oui.0[0], oui.0[1], oui.0[2]])
+    }
+}
+
+impl TryFrom<&str> for Oui {
+    type Error = ParseEuiError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if value.len() != 6 && value.len() != 8 {
+            return Err(ParseEuiError::InvalidLength {
+                length: value.len(),
+            });
+        }
+
+        let mut result = [0; 3];
+        string_to_eui(value.as_bytes(), &mut result[..])?;
+
+        Ok(Oui(result))
+    }
+}
+
+impl FromStr for Oui {
+    type Err = ParseEuiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Oui::try_from(s)
+    }
+}
+
+impl Display for Oui {
+    /// Takes the same flags as the `Display` impls of the address types.
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        let (format, case) = display_format(f);
+
+        write_formatted(f, &self.0, format, case)
+    }
+}
+
+pub(crate) const fn fnv1a(bytes: &[u8]) -> u32 {
+    let mut hash: u32 = 0x811c_9dc5;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        hash = (hash ^ bytes[i] as u32).wrapping_mul(0x0100_0193);
+        i += 1;
+    }
+
+    hash
+}
+
+impl Eui48 {
+    pub const fn oui(&self) -> Oui {
+        Oui([self.0[0], self.0[1], self.0[2]])
+    }
+
+    /// Address from a vendor prefix and the NIC specific part. The upper eight
+    /// bits of `nic` are ignored.
+    pub const fn from_oui_and_nic(oui: Oui, nic: u32) -> Self {
+        let [_, n1, n2, n3] = nic.to_be_bytes();
+
+        Eui([oui.0[0], oui.0[1], oui.0[2], n1, n2, n3])
+    }
+
+    /// Deterministic address in the block of `oui`, e.g. from a chip's unique
+    /// ID on boards without a burned-in MAC.
+    ///
+    /// The NIC specific part is the 32-bit FNV-1a hash of `seed` xor-folded to
+    /// 24 bits, so the same seed always gives the same address.
+    pub fn derive_from(oui: Oui, seed: &[u8]) -> Self {
+        let hash = fnv1a(seed);
+
+        Eui48::from_oui_and_nic(oui, hash >> 24 ^ hash & 0xff_ffff)
+    }
+
+    pub fn has_oui(&self, oui: Oui) -> bool {
+        self.oui() == oui
+    }
+}
+
+impl Eui64 {
+    pub const fn oui(&self) -> Oui {
+        Oui([self.0[0], self.0[1], self.0[2]])
+    }
+
+    /// Company ID and the 40-bit extension identifier following it.
+    pub const fn split(&self) -> (Oui, u64) {
+        let [o1, o2, o3, e1, e2, e3, e4, e5] = self.0;
+
+        (
+            Oui([o1, o2, o3]),
+            u64::from_be_bytes([0, 0, 0, e1, e2, e3, e4, e5]),
+        )
+    }
+
+    /// Inverse of [`split`](Eui64::split), e.g. to number DevEUIs in an
+    /// assigned block.
+    pub const fn from_parts(oui: Oui, extension: u64) -> Result<Self, ExtensionRangeError> {
+        if extension > 0xff_ffff_ffff {
+            return Err(ExtensionRangeError);
+        }
+
+        let [_, _, _, e1, e2, e3, e4, e5] = extension.to_be_bytes();
+
+        Ok(Eui([oui.0[0], oui.0[1], oui.0[2], e1, e2, e3, e4, e5]))
+    }
+
+    pub fn has_oui(&self, oui: Oui) -> bool {
+        self.oui() == oui
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::{Eui48, Eui64, ExtensionRangeError, Oui, ParseEuiError, RegistryKind};
+    use std::format;
+
+    #[test]
+    fn test_eui48_oui() {
+        let eui48 = Eui48::from(85204980412143);
+
+        assert_eq!(eui48.oui(), Oui::from_bytes([0x4d, 0x7e, 0x54]));
+        assert!(eui48.has_oui(Oui::from(0x4D7E54)));
+        assert!(!eui48.has_oui(Oui::from(0x4D7E55)));
+    }
+
+    #[test]
+    fn test_registry_kind() {
+        assert_eq!(Oui::from(0x4D7E54).registry_kind(), RegistryKind::Universal);
+        assert_eq!(Oui::from(0xDA7E54).registry_kind(), RegistryKind::Cid);
+        assert_eq!(Oui::from(0x4F7E54).registry_kind(), RegistryKind::Local);
+
+        let registry = [(Oui::from(0x4D7E54), "Example")];
+
+        assert_eq!(
+            Oui::from(0x4D7E54).registry_kind_in(&registry[..]),
+            RegistryKind::MaL
+        );
+        assert_eq!(
+            Oui::from(0x4D7E55).registry_kind_in(&registry[..]),
+            RegistryKind::Universal
+        );
+        assert_eq!(
+            Oui::from(0xDA7E54).registry_kind_in(&registry[..]),
+            RegistryKind::Cid
+        );
+    }
+
+    #[test]
+    fn test_eui64_oui() {
+        let eui64 = Eui64::from(5583992946972634863);
+
+        assert_eq!(u32::from(eui64.oui()), 0x4D7E54);
+        assert!(eui64.has_oui(Oui::from(0x4D7E54)));
+    }
+
+    #[test]
+    fn test_eui64_split_and_from_parts() {
+        let eui64 = Eui64::from(5583992946972634863);
+
+        assert_eq!(eui64.split(), (Oui::from(0x4D7E54), 0x0000972EEF));
+        assert_eq!(
+            Eui64::from_parts(Oui::from(0x4D7E54), 0x0000972EEF),
+            Ok(eui64)
+        );
+        assert_eq!(
+            Eui64::from_parts(Oui::from(0x4D7E54), 0xff_ffff_ffff),
+            Ok(Eui64::from(0x4D7E54FFFFFFFFFF))
+        );
+        assert_eq!(
+            Eui64::from_parts(Oui::from(0x4D7E54), 0x100_0000_0000),
+            Err(ExtensionRangeError)
+        );
+    }
+
+    #[test]
+    fn test_eui48_from_oui_and_nic() {
+        let eui48 = Eui48::from_oui_and_nic(Oui::from(0x4D7E54), 0xFF972EEF);
+
+        assert_eq!(eui48, Eui48::from(85204980412143));
+    }
+
+    #[test]
+    fn test_eui48_derive_from() {
+        let oui = Oui::from(0x4D7E54);
+        let eui48 = Eui48::derive_from(oui, b"chip-0001");
+
+        assert!(eui48.has_oui(oui));
+        assert_eq!(eui48, Eui48::derive_from(oui, b"chip-0001"));
+        assert_ne!(eui48, Eui48::derive_from(oui, b"chip-0002"));
+        assert_eq!(Eui48::derive_from(oui, b""), Eui48::from(0x4D7E541C9D44));
+        assert_eq!(Eui48::derive_from(oui, b"a"), Eui48::from(0x4D7E540C29C8));
+    }
+
+    #[test]
+    fn test_oui_to_string() {
+        let oui = Oui::from(0x4D7E54);
+
+        assert_eq!(oui.to_string(), "4D-7E-54");
+        assert_eq!(format!("{}", oui), "4D-7E-54");
+        assert_eq!(format!("{:#}", oui), "4d:7e:54");
+    }
+
+    #[test]
+    fn test_oui_from_str() {
+        assert_eq!("4d7e54".parse::<Oui>(), Ok(Oui::from(0x4D7E54)));
+        assert_eq!("4D:7E:54".parse::<Oui>(), Ok(Oui::from(0x4D7E54)));
+        assert_eq!(
+            "4D:7E:5".parse::<Oui>(),
+            Err(ParseEuiError::InvalidLength { length: 7 })
+        );
+        assert_eq!(
+            "4D:7E:5X".parse::<Oui>(),
+            Err(ParseEuiError::InvalidChar {
+                char: 'X',
+                position: 7
+            })
+        );
+    }
+}
@@ -0,0 +1,74 @@
+//! Generative tests of the parsers against the formatters.
+extern crate std;
+
+use crate::{Case, Eui48, Eui64, EuiFormat, LinkLayerAddr};
+use core::convert::TryFrom;
+use proptest::prelude::*;
+
+const FORMATS: [EuiFormat; 4] = [
+    EuiFormat::Colon,
+    EuiFormat::Hyphen,
+    EuiFormat::CiscoDot,
+    EuiFormat::Bare,
+];
+
+fn format_and_case() -> impl Strategy<Value = (EuiFormat, Case)> {
+    (
+        proptest::sample::select(&FORMATS[..]),
+        prop_oneof![Just(Case::Lower), Just(Case::Upper)],
+    )
+}
+
+proptest! {
+    #[test]
+    fn test_eui48_format_round_trip(bytes in any::<[u8; 6]>(), (format, case) in format_and_case()) {
+        let eui48 = Eui48::from(bytes);
+        let string = eui48.format(format, case);
+
+        prop_assert_eq!(Eui48::try_from(string.as_str()), Ok(eui48));
+        prop_assert_eq!(Eui48::parse_ascii(string.as_bytes()), Ok(eui48));
+        prop_assert_eq!(Eui48::parse_lenient(&string), Ok(eui48));
+        prop_assert_eq!(
+            LinkLayerAddr::try_from(string.as_str()),
+            Ok(LinkLayerAddr::Eui48(eui48))
+        );
+    }
+
+    #[test]
+    fn test_eui64_format_round_trip(bytes in any::<[u8; 8]>(), (format, case) in format_and_case()) {
+        let eui64 = Eui64::from(bytes);
+        let string = eui64.format(format, case);
+
+        prop_assert_eq!(Eui64::try_from(string.as_str()), Ok(eui64));
+        prop_assert_eq!(Eui64::parse_lenient(&string), Ok(eui64));
+    }
+
+    #[test]
+    fn test_parse_never_panics(input in "\\PC{0,32}") {
+        let _ = Eui48::try_from(input.as_str());
+        let _ = Eui64::try_from(input.as_str());
+        let _ = Eui48::parse_lenient(&input);
+        let _ = Eui64::parse_lenient(&input);
+        let _ = LinkLayerAddr::try_from(input.as_str());
+    }
+
+    #[test]
+    fn test_parse_ascii_never_panics(input in proptest::collection::vec(any::<u8>(), 0..24)) {
+        if let Ok(eui48) = Eui48::parse_ascii(&input) {
+            let string = std::str::from_utf8(&input).unwrap();
+
+            prop_assert_eq!(Eui48::try_from(string), Ok(eui48));
+        }
+
+        let _ = Eui64::parse_ascii(&input);
+    }
+
+    #[test]
+    fn test_parse_error_positions_in_bounds(input in "[0-9a-fA-F:.\\- g]{12,23}") {
+        if let Err(error) = Eui48::try_from(input.as_str()) {
+            if let Some(position) = error.position() {
+                prop_assert!(position < input.len());
+            }
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use crate::{Eui48, Eui64, NicOverflowError};
+
+const EUI48_MAX: u64 = 0xffff_ffff_ffff;
+
+impl Eui48 {
+    /// Adds `n` treating the address as a 48-bit integer, `None` on overflow.
+    /// Carries into the OUI, see [`checked_add_in_oui`](Self::checked_add_in_oui)
+    /// to stay within the block.
+    pub fn checked_add(&self, n: u64) -> Option<Self> {
+        match u64::from(*self).checked_add(n) {
+            Some(value) if value <= EUI48_MAX => Some(Eui48::from(value)),
+            _ => None,
+        }
+    }
+
+    pub fn checked_sub(&self, n: u64) -> Option<Self> {
+        u64::from(*self).checked_sub(n).map(Eui48::from)
+    }
+
+    #[inline]
+    pub fn successor(&self) -> Option<Self> {
+        self.checked_add(1)
+    }
+
+    #[inline]
+    pub fn predecessor(&self) -> Option<Self> {
+        self.checked_sub(1)
+    }
+
+    /// Subtracts `n` from the NIC specific part, failing instead of borrowing
+    /// from the OUI.
+    pub fn checked_sub_in_oui(&self, n: u64) -> Result<Self, NicOverflowError> {
+        let value = u64::from(*self);
+
+        match (value & 0xff_ffff).checked_sub(n) {
+            Some(nic) => Ok(Eui48::from(value & !0xff_ffff | nic)),
+            None => Err(NicOverflowError),
+        }
+    }
+
+    #[inline]
+    pub fn checked_prev_in_oui(&self) -> Result<Self, NicOverflowError> {
+        self.checked_sub_in_oui(1)
+    }
+
+    /// Addresses from this one to the last sharing its OUI, so handing them out
+    /// never carries into the next vendor block.
+    pub fn oui_range(&self) -> EuiRange<Eui48> {
+        EuiRange::new(*self, Eui48::from(u64::from(*self) | 0xff_ffff))
+    }
+}
+
+impl Eui64 {
+    /// Adds `n` treating the address as a 64-bit integer, `None` on overflow.
+    /// Carries into the OUI, see [`checked_add_in_oui`](Self::checked_add_in_oui)
+    /// to stay within the block.
+    pub fn checked_add(&self, n: u64) -> Option<Self> {
+        u64::from(*self).checked_add(n).map(Eui64::from)
+    }
+
+    pub fn checked_sub(&self, n: u64) -> Option<Self> {
+        u64::from(*self).checked_sub(n).map(Eui64::from)
+    }
+
+    #[inline]
+    pub fn successor(&self) -> Option<Self> {
+        self.checked_add(1)
+    }
+
+    #[inline]
+    pub fn predecessor(&self) -> Option<Self> {
+        self.checked_sub(1)
+    }
+
+    /// Subtracts `n` from the 40-bit extension identifier, failing instead of
+    /// borrowing from the OUI.
+    pub fn checked_sub_in_oui(&self, n: u64) -> Result<Self, NicOverflowError> {
+        let value = u64::from(*self);
+
+        match (value & 0xff_ffff_ffff).checked_sub(n) {
+            Some(nic) => Ok(Eui64::from(value & !0xff_ffff_ffff | nic)),
+            None => Err(NicOverflowError),
+        }
+    }
+
+    #[inline]
+    pub fn checked_prev_in_oui(&self) -> Result<Self, NicOverflowError> {
+        self.checked_sub_in_oui(1)
+    }
+
+    /// Addresses from this one to the last sharing its OUI, so handing them out
+    /// never carries into the next vendor block.
+    pub fn oui_range(&self) -> EuiRange<Eui64> {
+        EuiRange::new(*self, Eui64::from(u64::from(*self) | 0xff_ffff_ffff))
+    }
+}
+
+/// Iterator over the inclusive range `start..=end` of addresses, e.g. to hand
+/// out sequential MACs from a base address.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct EuiRange<T> {
+    start: T,
+    end: T,
+    exhausted: bool,
+}
+
+impl<T: Copy + Ord> EuiRange<T> {
+    /// Empty if `start` is greater than `end`.
+    pub fn new(start: T, end: T) -> Self {
+        EuiRange {
+            start,
+            end,
+            exhausted: start > end,
+        }
+    }
+
+    pub fn contains(&self, eui: &T) -> bool {
+        !self.exhausted && self.start <= *eui && *eui <= self.end
+    }
+}
+
+impl Iterator for EuiRange<Eui48> {
+    type Item = Eui48;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+
+        let current = self.start;
+
+        match current.successor() {
+            Some(next) if next <= self.end => self.start = next,
+            _ => self.exhausted = true,
+        }
+
+        Some(current)
+    }
+}
+
+impl Iterator for EuiRange<Eui64> {
+    type Item = Eui64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+
+        let current = self.start;
+
+        match current.successor() {
+            Some(next) if next <= self.end => self.start = next,
+            _ => self.exhausted = true,
+        }
+
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui64, EuiRange, NicOverflowError};
+
+    #[test]
+    fn test_eui48_checked_add_sub() {
+        let eui48 = Eui48::from(0x4D7E54FFFFFF);
+
+        assert_eq!(eui48.successor(), Some(Eui48::from(0x4D7E55000000)));
+        assert_eq!(
+            eui48.checked_sub(0xFFFFFF),
+            Some(Eui48::from(0x4D7E54000000))
+        );
+        assert_eq!(Eui48::BROADCAST.successor(), None);
+        assert_eq!(Eui48::NIL.predecessor(), None);
+        assert_eq!(Eui48::NIL.checked_add(u64::MAX), None);
+    }
+
+    #[test]
+    fn test_eui64_checked_add_sub() {
+        assert_eq!(Eui64::from(1).predecessor(), Some(Eui64::NIL));
+        assert_eq!(Eui64::BROADCAST.successor(), None);
+        assert_eq!(Eui64::from(2).checked_add(3), Some(Eui64::from(5)));
+    }
+
+    #[test]
+    fn test_checked_sub_in_oui() {
+        let eui48 = Eui48::from(0x4D7E54000001);
+
+        assert_eq!(eui48.checked_prev_in_oui(), Ok(Eui48::from(0x4D7E54000000)));
+        assert_eq!(eui48.checked_sub_in_oui(2), Err(NicOverflowError));
+        assert_eq!(
+            Eui64::from(0x4D7E540000000000).checked_prev_in_oui(),
+            Err(NicOverflowError)
+        );
+    }
+
+    #[test]
+    fn test_eui48_range() {
+        let mut range = EuiRange::new(Eui48::from(0x4D7E54FFFFFE), Eui48::from(0x4D7E55000000));
+
+        assert!(range.contains(&Eui48::from(0x4D7E54FFFFFF)));
+        assert_eq!(range.next(), Some(Eui48::from(0x4D7E54FFFFFE)));
+        assert_eq!(range.next(), Some(Eui48::from(0x4D7E54FFFFFF)));
+        assert_eq!(range.next(), Some(Eui48::from(0x4D7E55000000)));
+        assert_eq!(range.next(), None);
+
+        assert_eq!(EuiRange::new(Eui48::from(2), Eui48::from(1)).count(), 0);
+        assert_eq!(
+            EuiRange::new(Eui48::from(0xFFFFFFFFFFFE), Eui48::BROADCAST).count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_oui_range() {
+        let range = Eui48::from(0x4D7E54FFFFFD).oui_range();
+
+        assert_eq!(range.count(), 3);
+        assert_eq!(
+            Eui64::from(0x4D7E54FFFFFFFFFF).oui_range().last(),
+            Some(Eui64::from(0x4D7E54FFFFFFFFFF))
+        );
+    }
+}
@@ -0,0 +1,178 @@
+//! Fixed-capacity per-device table keyed by [`Eui48`](crate::Eui48) or
+//! [`Eui64`](crate::Eui64).
+//!
+//! With the `serde` feature the whole table serializes as a map from the
+//! address string to the value, so commissioning data can be stored and
+//! reloaded as one structure.
+//!
+//! # Example
+//!
+//! ```rust
+//! use eui::registry::DeviceRegistry;
+//! use eui::Eui48;
+//!
+//! let mut registry = DeviceRegistry::<Eui48, &str, 4>::new();
+//! let eui48 = Eui48::from(85204980412143);
+//!
+//! registry.insert(eui48, "kitchen").unwrap();
+//!
+//! assert_eq!(registry.get(&eui48), Some(&"kitchen"));
+//! ```
+use core::fmt;
+use heapless::Vec;
+
+pub struct DeviceRegistry<K, V, const N: usize> {
+    entries: Vec<(K, V), N>,
+}
+
+impl<K, V, const N: usize> DeviceRegistry<K, V, N>
+where
+    K: PartialEq,
+{
+    pub fn new() -> Self {
+        DeviceRegistry {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Inserts or replaces the value for `key`, returning the previous one.
+    /// Gives the pair back if the registry is full.
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>, (K, V)> {
+        match self.get_mut(&key) {
+            Some(current) => Ok(Some(core::mem::replace(current, value))),
+            None => self.entries.push((key, value)).map(|_| None),
+        }
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.entries
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value)
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.entries
+            .iter_mut()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value)
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let index = self.entries.iter().position(|(k, _)| k == key)?;
+
+        Some(self.entries.swap_remove(index).1)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries.iter().map(|(key, value)| (key, value))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.entries.capacity()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear()
+    }
+}
+
+impl<K, V, const N: usize> Default for DeviceRegistry<K, V, N>
+where
+    K: PartialEq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, const N: usize> PartialEq for DeviceRegistry<K, V, N>
+where
+    K: PartialEq,
+    V: PartialEq,
+{
+    /// Registries are equal when they hold the same pairs, in any order.
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key) == Some(value))
+    }
+}
+
+impl<K, V, const N: usize> fmt::Debug for DeviceRegistry<K, V, N>
+where
+    K: PartialEq + fmt::Debug,
+    V: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DeviceRegistry;
+    use crate::{Eui48, Eui64};
+
+    #[test]
+    fn test_registry_insert_and_replace() {
+        let mut registry = DeviceRegistry::<Eui48, u8, 2>::new();
+        let eui48 = Eui48::from(85204980412143);
+
+        assert_eq!(registry.insert(eui48, 1), Ok(None));
+        assert_eq!(registry.insert(eui48, 2), Ok(Some(1)));
+        assert_eq!(registry.get(&eui48), Some(&2));
+        assert_eq!(registry.len(), 1);
+    }
+
+    #[test]
+    fn test_registry_eq_ignores_order() {
+        let mut registry_1 = DeviceRegistry::<Eui48, u8, 2>::new();
+        registry_1.insert(Eui48::from(1), 1).unwrap();
+        registry_1.insert(Eui48::from(2), 2).unwrap();
+
+        let mut registry_2 = DeviceRegistry::<Eui48, u8, 2>::new();
+        registry_2.insert(Eui48::from(2), 2).unwrap();
+        registry_2.insert(Eui48::from(1), 1).unwrap();
+
+        assert_eq!(registry_1, registry_2);
+
+        registry_2.insert(Eui48::from(1), 3).unwrap();
+        assert_ne!(registry_1, registry_2);
+    }
+
+    #[test]
+    fn test_registry_full() {
+        let mut registry = DeviceRegistry::<Eui64, u8, 1>::new();
+
+        registry.insert(Eui64::from(1), 1).unwrap();
+
+        assert_eq!(registry.insert(Eui64::from(2), 2), Err((Eui64::from(2), 2)));
+        assert_eq!(registry.insert(Eui64::from(1), 3), Ok(Some(1)));
+    }
+
+    #[test]
+    fn test_registry_remove() {
+        let mut registry = DeviceRegistry::<Eui48, u8, 2>::new();
+        let eui48 = Eui48::from(85204980412143);
+
+        registry.insert(eui48, 1).unwrap();
+
+        assert_eq!(registry.remove(&eui48), Some(1));
+        assert_eq!(registry.remove(&eui48), None);
+        assert!(registry.is_empty());
+    }
+}
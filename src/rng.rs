@@ -0,0 +1,132 @@
+//! Random address generation from any entropy source, e.g. a hardware TRNG
+//! peripheral, without depending on `rand`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use eui::rng::EuiRngSource;
+//! use eui::Eui48;
+//!
+//! struct Trng;
+//!
+//! impl EuiRngSource for Trng {
+//!     fn fill_bytes(&mut self, dest: &mut [u8]) {
+//!         // Read from the peripheral here.
+//!         dest.iter_mut().for_each(|byte| *byte = 0x4d);
+//!     }
+//! }
+//!
+//! let eui48 = Eui48::random_local_unicast_from(&mut Trng);
+//!
+//! assert_eq!(eui48.to_string(), "4E-4D-4D-4D-4D-4D");
+//! ```
+use crate::{Eui, Eui48, Eui64};
+
+/// Source of random bytes.
+pub trait EuiRngSource {
+    fn fill_bytes(&mut self, dest: &mut [u8]);
+}
+
+impl<R: EuiRngSource + ?Sized> EuiRngSource for &mut R {
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        (**self).fill_bytes(dest)
+    }
+}
+
+impl Eui48 {
+    /// Random locally administered unicast address, as used for privacy MACs.
+    pub fn random_local_unicast_from(source: &mut impl EuiRngSource) -> Self {
+        let mut bytes = [0; 6];
+
+        source.fill_bytes(&mut bytes);
+
+        Eui(bytes).with_local().with_unicast()
+    }
+
+    /// Random locally administered unicast address from a `rand_core` RNG.
+    #[cfg(feature = "rand_core")]
+    pub fn random_local(rng: &mut impl rand_core::RngCore) -> Self {
+        let mut bytes = [0; 6];
+
+        rng.fill_bytes(&mut bytes);
+
+        Eui(bytes).with_local().with_unicast()
+    }
+}
+
+impl Eui64 {
+    /// Random locally administered unicast address.
+    pub fn random_local_unicast_from(source: &mut impl EuiRngSource) -> Self {
+        let mut bytes = [0; 8];
+
+        source.fill_bytes(&mut bytes);
+
+        Eui(bytes).with_local().with_unicast()
+    }
+
+    /// Random locally administered unicast address from a `rand_core` RNG.
+    #[cfg(feature = "rand_core")]
+    pub fn random_local(rng: &mut impl rand_core::RngCore) -> Self {
+        let mut bytes = [0; 8];
+
+        rng.fill_bytes(&mut bytes);
+
+        Eui(bytes).with_local().with_unicast()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EuiRngSource;
+    use crate::{Eui48, Eui64};
+
+    struct Counter(u8);
+
+    impl EuiRngSource for Counter {
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for byte in dest {
+                *byte = self.0;
+                self.0 = self.0.wrapping_add(1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_eui48_random_local_unicast_from() {
+        let eui48 = Eui48::random_local_unicast_from(&mut Counter(0xff));
+
+        assert_eq!(eui48, Eui48::from(0xFE0001020304));
+    }
+
+    #[test]
+    fn test_eui64_random_local_unicast_from() {
+        let eui64 = Eui64::random_local_unicast_from(&mut Counter(0x4d));
+
+        assert_eq!(eui64, Eui64::from(0x4E4E4F5051525354));
+    }
+
+    #[cfg(feature = "rand_core")]
+    impl rand_core::RngCore for Counter {
+        fn next_u32(&mut self) -> u32 {
+            rand_core::impls::next_u32_via_fill(self)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            rand_core::impls::next_u64_via_fill(self)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            EuiRngSource::fill_bytes(self, dest)
+        }
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn test_random_local() {
+        let eui48 = Eui48::random_local(&mut Counter(0xff));
+        let eui64 = Eui64::random_local(&mut Counter(0x4d));
+
+        assert_eq!(eui48, Eui48::from(0xFE0001020304));
+        assert_eq!(eui64, Eui64::from(0x4E4E4F5051525354));
+    }
+}
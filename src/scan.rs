@@ -0,0 +1,116 @@
+//! Heuristic search for MAC addresses in raw byte buffers such as memory
+//! dumps or flash images.
+//!
+//! Every offset holding six bytes that are neither all zeros nor the
+//! broadcast address is a candidate. Restricting the scan to known OUIs cuts
+//! down the noise considerably.
+//!
+//! # Example
+//!
+//! ```rust
+//! use eui::scan::MacScanner;
+//! use eui::Eui48;
+//!
+//! let image = [0xff, 0xff, 0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef, 0x00];
+//! let mut candidates = MacScanner::new(&image).with_ouis(&[0x4D7E54]);
+//!
+//! assert_eq!(candidates.next(), Some((2, Eui48::from(85204980412143))));
+//! assert_eq!(candidates.next(), None);
+//! ```
+use crate::Eui48;
+use core::convert::TryFrom;
+
+/// Iterator over `(offset, address)` pairs of plausible MAC addresses.
+#[derive(Clone, Debug)]
+pub struct MacScanner<'a> {
+    buffer: &'a [u8],
+    offset: usize,
+    ouis: Option<&'a [u32]>,
+}
+
+impl<'a> MacScanner<'a> {
+    pub fn new(buffer: &'a [u8]) -> Self {
+        MacScanner {
+            buffer,
+            offset: 0,
+            ouis: None,
+        }
+    }
+
+    /// Only yield addresses whose [`oui_u32`](Eui48::oui_u32) is in `ouis`.
+    pub fn with_ouis(mut self, ouis: &'a [u32]) -> Self {
+        self.ouis = Some(ouis);
+        self
+    }
+
+    fn is_plausible(&self, eui48: &Eui48) -> bool {
+        if eui48.is_nil() || eui48.is_broadcast() {
+            return false;
+        }
+
+        match self.ouis {
+            Some(ouis) => ouis.contains(&eui48.oui_u32()),
+            None => true,
+        }
+    }
+}
+
+impl<'a> Iterator for MacScanner<'a> {
+    type Item = (usize, Eui48);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.offset + 6 <= self.buffer.len() {
+            let offset = self.offset;
+            let eui48 = Eui48::try_from(&self.buffer[offset..offset + 6]).unwrap();
+
+            self.offset += 1;
+
+            if self.is_plausible(&eui48) {
+                return Some((offset, eui48));
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MacScanner;
+    use crate::Eui48;
+
+    #[test]
+    fn test_scan_skips_nil_and_broadcast() {
+        let buffer = [0u8; 8];
+        assert_eq!(MacScanner::new(&buffer).next(), None);
+
+        let buffer = [0xffu8; 8];
+        assert_eq!(MacScanner::new(&buffer).next(), None);
+    }
+
+    #[test]
+    fn test_scan_yields_every_offset() {
+        let buffer = [0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00];
+        let mut scanner = MacScanner::new(&buffer);
+
+        assert_eq!(scanner.next(), Some((0, Eui48::from(0x000000000001))));
+        assert_eq!(scanner.next(), Some((1, Eui48::from(0x000000000100))));
+        assert_eq!(scanner.next(), None);
+    }
+
+    #[test]
+    fn test_scan_with_ouis() {
+        let buffer = [
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef,
+        ];
+        let mut scanner = MacScanner::new(&buffer).with_ouis(&[0x4D7E54]);
+
+        assert_eq!(scanner.next(), Some((6, Eui48::from(85204980412143))));
+        assert_eq!(scanner.next(), None);
+    }
+
+    #[test]
+    fn test_scan_short_buffer() {
+        assert_eq!(MacScanner::new(&[0x4d, 0x7e, 0x54]).next(), None);
+    }
+}
@@ -1,32 +1,181 @@
-use crate::{Eui48, Eui64};
+use crate::registry::DeviceRegistry;
+use crate::{Eui, Eui60, LinkLayerAddr, Oui};
 use serde::{Serialize, Serializer};
 
-impl Serialize for Eui48 {
+/// Whether to write the textual form. Always without the `serde-bytes`
+/// feature, otherwise only for human-readable formats.
+pub(crate) fn human_readable<S: Serializer>(serializer: &S) -> bool {
+    !cfg!(feature = "serde-bytes") || serializer.is_human_readable()
+}
+
+impl<const N: usize> Serialize for Eui<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if human_readable(&serializer) {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+}
+
+impl Serialize for Eui60 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if human_readable(&serializer) {
+            serializer.serialize_str(&self.to_string())
+        } else {
+            serializer.serialize_u64(self.0)
+        }
+    }
+}
+
+impl Serialize for Oui {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if human_readable(&serializer) {
+            serializer.serialize_str(&self.to_string())
+        } else {
+            serializer.serialize_bytes(&self.0)
+        }
+    }
+}
+
+impl Serialize for LinkLayerAddr {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        serializer.serialize_str(&self.to_string())
+        match self {
+            LinkLayerAddr::Eui48(eui48) => eui48.serialize(serializer),
+            LinkLayerAddr::Eui64(eui64) => eui64.serialize(serializer),
+        }
     }
 }
 
-impl Serialize for Eui64 {
+impl<K, V, const N: usize> Serialize for DeviceRegistry<K, V, N>
+where
+    K: Serialize + PartialEq,
+    V: Serialize,
+{
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        serializer.serialize_str(&self.to_string())
+        serializer.collect_map(self.iter())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Eui48, Eui64};
-    use serde_test::{assert_ser_tokens, Token};
+    #[cfg(not(feature = "serde-bytes"))]
+    use crate::registry::DeviceRegistry;
+    use crate::{Eui48, Eui60, Eui64, LinkLayerAddr, Oui};
+    use serde_test::{assert_ser_tokens, Configure, Token};
 
+    #[cfg(not(feature = "serde-bytes"))]
     #[test]
     fn test_eui48_serialize() {
         let eui48 = Eui48::from(85204980412143);
         assert_ser_tokens(&eui48, &[Token::String("4D-7E-54-97-2E-EF")]);
     }
 
+    #[cfg(not(feature = "serde-bytes"))]
     #[test]
     fn test_eui64_serialize() {
         let eui64 = Eui64::from(5583992946972634863);
         assert_ser_tokens(&eui64, &[Token::String("4D-7E-54-00-00-97-2E-EF")]);
     }
+
+    #[cfg(feature = "serde-bytes")]
+    #[test]
+    fn test_eui48_serialize_compact() {
+        let eui48 = Eui48::from(85204980412143);
+        assert_ser_tokens(
+            &eui48.compact(),
+            &[Token::Bytes(&[0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef])],
+        );
+    }
+
+    #[cfg(feature = "serde-bytes")]
+    #[test]
+    fn test_eui64_serialize_compact() {
+        let eui64 = Eui64::from(5583992946972634863);
+        assert_ser_tokens(
+            &eui64.compact(),
+            &[Token::Bytes(&[
+                0x4d, 0x7e, 0x54, 0x00, 0x00, 0x97, 0x2e, 0xef,
+            ])],
+        );
+    }
+
+    #[test]
+    fn test_eui60_serialize() {
+        let eui60 = Eui60::from(0x4D7E54123456789);
+
+        assert_ser_tokens(&eui60.readable(), &[Token::String("4D7E54123456789")]);
+    }
+
+    #[cfg(feature = "serde-bytes")]
+    #[test]
+    fn test_eui60_serialize_compact() {
+        let eui60 = Eui60::from(0x4D7E54123456789);
+        assert_ser_tokens(&eui60.compact(), &[Token::U64(0x4D7E54123456789)]);
+    }
+
+    #[cfg(not(feature = "serde-bytes"))]
+    #[test]
+    fn test_oui_serialize() {
+        assert_ser_tokens(&Oui::from(0x4D7E54), &[Token::String("4D-7E-54")]);
+    }
+
+    #[cfg(feature = "serde-bytes")]
+    #[test]
+    fn test_oui_serialize_compact() {
+        assert_ser_tokens(
+            &Oui::from(0x4D7E54).compact(),
+            &[Token::Bytes(&[0x4d, 0x7e, 0x54])],
+        );
+    }
+
+    #[cfg(not(feature = "serde-bytes"))]
+    #[test]
+    fn test_link_layer_addr_serialize() {
+        assert_ser_tokens(
+            &LinkLayerAddr::from(Eui48::from(85204980412143)),
+            &[Token::String("4D-7E-54-97-2E-EF")],
+        );
+
+        assert_ser_tokens(
+            &LinkLayerAddr::from(Eui64::from(5583992946972634863)),
+            &[Token::String("4D-7E-54-00-00-97-2E-EF")],
+        );
+    }
+
+    #[cfg(feature = "serde-bytes")]
+    #[test]
+    fn test_link_layer_addr_serialize_compact() {
+        assert_ser_tokens(
+            &LinkLayerAddr::from(Eui48::from(85204980412143)).compact(),
+            &[Token::Bytes(&[0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef])],
+        );
+
+        assert_ser_tokens(
+            &LinkLayerAddr::from(Eui64::from(5583992946972634863)).compact(),
+            &[Token::Bytes(&[
+                0x4d, 0x7e, 0x54, 0x00, 0x00, 0x97, 0x2e, 0xef,
+            ])],
+        );
+    }
+
+    #[cfg(not(feature = "serde-bytes"))]
+    #[test]
+    fn test_registry_serialize() {
+        let mut registry = DeviceRegistry::<Eui48, u8, 2>::new();
+        registry.insert(Eui48::from(85204980412143), 1).unwrap();
+        registry.insert(Eui48::from(1), 2).unwrap();
+
+        assert_ser_tokens(
+            &registry,
+            &[
+                Token::Map { len: Some(2) },
+                Token::String("4D-7E-54-97-2E-EF"),
+                Token::U8(1),
+                Token::String("00-00-00-00-00-01"),
+                Token::U8(2),
+                Token::MapEnd,
+            ],
+        );
+    }
 }
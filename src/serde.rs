@@ -0,0 +1,194 @@
+//! Modules for `#[serde(with = "...")]` that pick the textual format of a
+//! field instead of the default `4D-7E-54-97-2E-EF`.
+//!
+//! With the `serde-bytes` feature only human-readable formats are affected;
+//! compact ones keep the byte representation. Deserializing accepts every
+//! format, as usual.
+//!
+//! # Example
+//!
+//! ```rust
+//! use eui::Eui48;
+//! use serde::Serialize;
+//!
+//! #[derive(Serialize)]
+//! struct Interface {
+//!     #[serde(with = "eui::serde::colon_upper")]
+//!     mac: Eui48,
+//! }
+//! ```
+use crate::format::{write_formatted, Case, EuiFormat};
+use crate::{Eui48, Eui64, LinkLayerAddr, Oui};
+use heapless::String;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Address types usable with the modules of [`eui::serde`](self).
+pub trait Address: Serialize + private::Sealed {
+    #[doc(hidden)]
+    fn octets(&self) -> &[u8];
+}
+
+impl private::Sealed for Eui48 {}
+impl private::Sealed for Eui64 {}
+impl private::Sealed for Oui {}
+impl private::Sealed for LinkLayerAddr {}
+
+impl Address for Eui48 {
+    fn octets(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Address for Eui64 {
+    fn octets(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Address for Oui {
+    fn octets(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Address for LinkLayerAddr {
+    fn octets(&self) -> &[u8] {
+        match self {
+            LinkLayerAddr::Eui48(eui48) => &eui48.0,
+            LinkLayerAddr::Eui64(eui64) => &eui64.0,
+        }
+    }
+}
+
+fn serialize_as<T: Address, S: Serializer>(
+    value: &T,
+    serializer: S,
+    format: EuiFormat,
+    case: Case,
+) -> Result<S::Ok, S::Error> {
+    if !crate::ser::human_readable(&serializer) {
+        return value.serialize(serializer);
+    }
+
+    let mut string = String::<23>::new();
+
+    write_formatted(&mut string, value.octets(), format, case).expect("String is not long enough");
+
+    serializer.serialize_str(&string)
+}
+
+macro_rules! with_format {
+    ($($name:ident => $format:ident, $case:ident, $example:literal;)*) => {
+        $(
+            #[doc = concat!("Serializes as `", $example, "`.")]
+            pub mod $name {
+                use super::Address;
+                use crate::format::{Case, EuiFormat};
+                use serde::{Deserialize, Deserializer, Serializer};
+
+                pub fn serialize<T: Address, S: Serializer>(
+                    value: &T,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    super::serialize_as(value, serializer, EuiFormat::$format, Case::$case)
+                }
+
+                pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+                where
+                    T: Address + Deserialize<'de>,
+                    D: Deserializer<'de>,
+                {
+                    super::deserialize(deserializer)
+                }
+            }
+        )*
+    };
+}
+
+fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Address + Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    T::deserialize(deserializer)
+}
+
+with_format! {
+    colon_lower => Colon, Lower, "4d:7e:54:97:2e:ef";
+    colon_upper => Colon, Upper, "4D:7E:54:97:2E:EF";
+    hyphen_lower => Hyphen, Lower, "4d-7e-54-97-2e-ef";
+    hyphen_upper => Hyphen, Upper, "4D-7E-54-97-2E-EF";
+    cisco_dot => CiscoDot, Lower, "4d7e.5497.2eef";
+    bare_lower => Bare, Lower, "4d7e54972eef";
+    bare_upper => Bare, Upper, "4D7E54972EEF";
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui64, LinkLayerAddr};
+    use serde::{Deserialize, Serialize};
+    use serde_test::{assert_tokens, Configure, Token};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Interface {
+        #[serde(with = "crate::serde::colon_upper")]
+        mac: Eui48,
+        #[serde(with = "crate::serde::cisco_dot")]
+        eui64: Eui64,
+        #[serde(with = "crate::serde::bare_lower")]
+        address: LinkLayerAddr,
+    }
+
+    fn interface() -> Interface {
+        Interface {
+            mac: Eui48::from(85204980412143),
+            eui64: Eui64::from(5583992946972634863),
+            address: LinkLayerAddr::from(Eui48::from(85204980412143)),
+        }
+    }
+
+    #[test]
+    fn test_with_format_readable() {
+        assert_tokens(
+            &interface().readable(),
+            &[
+                Token::Struct {
+                    name: "Interface",
+                    len: 3,
+                },
+                Token::Str("mac"),
+                Token::Str("4D:7E:54:97:2E:EF"),
+                Token::Str("eui64"),
+                Token::Str("4d7e.5400.0097.2eef"),
+                Token::Str("address"),
+                Token::Str("4d7e54972eef"),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[cfg(feature = "serde-bytes")]
+    #[test]
+    fn test_with_format_compact() {
+        assert_tokens(
+            &interface().compact(),
+            &[
+                Token::Struct {
+                    name: "Interface",
+                    len: 3,
+                },
+                Token::Str("mac"),
+                Token::Bytes(&[0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef]),
+                Token::Str("eui64"),
+                Token::Bytes(&[0x4d, 0x7e, 0x54, 0x00, 0x00, 0x97, 0x2e, 0xef]),
+                Token::Str("address"),
+                Token::Bytes(&[0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef]),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
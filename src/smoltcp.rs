@@ -0,0 +1,137 @@
+use crate::{Eui, Eui48, Eui64};
+use core::convert::TryFrom;
+use smoltcp::wire::{EthernetAddress, HardwareAddress, Ieee802154Address};
+
+impl From<Eui48> for EthernetAddress {
+    fn from(eui48: Eui48) -> Self {
+        EthernetAddress(eui48.0)
+    }
+}
+
+impl From<EthernetAddress> for Eui48 {
+    fn from(address: EthernetAddress) -> Self {
+        Eui(address.0)
+    }
+}
+
+impl From<Eui64> for Ieee802154Address {
+    fn from(eui64: Eui64) -> Self {
+        Ieee802154Address::Extended(eui64.0)
+    }
+}
+
+impl TryFrom<Ieee802154Address> for Eui64 {
+    /// Absent and short addresses are given back unchanged.
+    type Error = Ieee802154Address;
+
+    fn try_from(address: Ieee802154Address) -> Result<Self, Self::Error> {
+        match address {
+            Ieee802154Address::Extended(bytes) => Ok(Eui(bytes)),
+            address => Err(address),
+        }
+    }
+}
+
+impl From<Eui64> for HardwareAddress {
+    fn from(eui64: Eui64) -> Self {
+        HardwareAddress::Ieee802154(eui64.into())
+    }
+}
+
+impl From<Eui48> for HardwareAddress {
+    fn from(eui48: Eui48) -> Self {
+        HardwareAddress::Ethernet(eui48.into())
+    }
+}
+
+impl TryFrom<HardwareAddress> for Eui48 {
+    /// Addresses other than Ethernet ones are given back unchanged.
+    type Error = HardwareAddress;
+
+    fn try_from(address: HardwareAddress) -> Result<Self, Self::Error> {
+        match address {
+            HardwareAddress::Ethernet(address) => Ok(address.into()),
+            // Which other variants exist depends on the enabled smoltcp features.
+            #[allow(unreachable_patterns)]
+            address => Err(address),
+        }
+    }
+}
+
+impl TryFrom<HardwareAddress> for Eui64 {
+    /// Addresses other than extended IEEE 802.15.4 ones are given back unchanged.
+    type Error = HardwareAddress;
+
+    fn try_from(address: HardwareAddress) -> Result<Self, Self::Error> {
+        match address {
+            HardwareAddress::Ieee802154(Ieee802154Address::Extended(bytes)) => Ok(Eui(bytes)),
+            // Which other variants exist depends on the enabled smoltcp features.
+            #[allow(unreachable_patterns)]
+            address => Err(address),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui64};
+    use core::convert::TryFrom;
+    use smoltcp::wire::{EthernetAddress, HardwareAddress, Ieee802154Address};
+
+    #[test]
+    fn test_eui48_to_ethernet_address() {
+        let eui48 = Eui48::from(85204980412143);
+        let address = EthernetAddress::from(eui48);
+
+        assert_eq!(
+            address,
+            EthernetAddress([0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef])
+        );
+        assert_eq!(Eui48::from(address), eui48);
+    }
+
+    #[test]
+    fn test_eui48_to_hardware_address() {
+        let eui48 = Eui48::from(85204980412143);
+
+        assert_eq!(Eui48::try_from(HardwareAddress::from(eui48)), Ok(eui48));
+
+        let eui64 = HardwareAddress::from(Eui64::from(5583992946972634863));
+        assert_eq!(Eui48::try_from(eui64), Err(eui64));
+    }
+
+    #[test]
+    fn test_eui64_to_ieee802154_address() {
+        let eui64 = Eui64::from(5583992946972634863);
+
+        assert_eq!(
+            Ieee802154Address::from(eui64),
+            Ieee802154Address::Extended([0x4d, 0x7e, 0x54, 0x00, 0x00, 0x97, 0x2e, 0xef])
+        );
+        assert_eq!(Eui64::try_from(Ieee802154Address::from(eui64)), Ok(eui64));
+    }
+
+    #[test]
+    fn test_eui64_try_from_short_ieee802154_address() {
+        let short = Ieee802154Address::Short([0x12, 0x34]);
+
+        assert_eq!(Eui64::try_from(short), Err(short));
+        assert_eq!(
+            Eui64::try_from(Ieee802154Address::Absent),
+            Err(Ieee802154Address::Absent)
+        );
+    }
+
+    #[test]
+    fn test_eui64_to_hardware_address() {
+        let eui64 = Eui64::from(5583992946972634863);
+
+        assert_eq!(Eui64::try_from(HardwareAddress::from(eui64)), Ok(eui64));
+
+        let short = HardwareAddress::Ieee802154(Ieee802154Address::Short([0x12, 0x34]));
+        assert_eq!(Eui64::try_from(short), Err(short));
+
+        let ethernet = HardwareAddress::from(Eui48::from(85204980412143));
+        assert_eq!(Eui64::try_from(ethernet), Err(ethernet));
+    }
+}
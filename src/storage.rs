@@ -0,0 +1,343 @@
+//! Persisting addresses with [`embedded-storage`](embedded_storage).
+//!
+//! An address is stored as a small record: a magic byte, the address length,
+//! the address octets in transmission order and a CRC-8 over everything
+//! before it. Reading rejects erased flash, foreign data, corrupted records and
+//! the nil and broadcast addresses, writing the latter two as well.
+//!
+//! # Example
+//!
+//! ```rust
+//! use eui::storage::{read_mac, write_mac};
+//! use eui::Eui48;
+//! # use embedded_storage::{ReadStorage, Storage};
+//! #
+//! # struct Flash([u8; 16]);
+//! #
+//! # impl ReadStorage for Flash {
+//! #     type Error = ();
+//! #
+//! #     fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), ()> {
+//! #         let offset = offset as usize;
+//! #         bytes.copy_from_slice(self.0.get(offset..offset + bytes.len()).ok_or(())?);
+//! #         Ok(())
+//! #     }
+//! #
+//! #     fn capacity(&self) -> usize {
+//! #         self.0.len()
+//! #     }
+//! # }
+//! #
+//! # impl Storage for Flash {
+//! #     fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), ()> {
+//! #         let offset = offset as usize;
+//! #         self.0.get_mut(offset..offset + bytes.len()).ok_or(())?.copy_from_slice(bytes);
+//! #         Ok(())
+//! #     }
+//! # }
+//! #
+//! # let mut flash = Flash([0xff; 16]);
+//! let eui48 = Eui48::from(85204980412143);
+//!
+//! write_mac(&mut flash, 4, &eui48)?;
+//! assert_eq!(read_mac(&mut flash, 4)?, eui48);
+//! # Ok::<(), eui::storage::StorageError<()>>(())
+//! ```
+use crate::{Eui, Eui48, Eui64};
+use core::fmt::{self, Debug, Display, Formatter};
+use embedded_storage::{ReadStorage, Storage};
+
+const MAGIC: u8 = 0xE1;
+const MAX_RECORD_SIZE: usize = 8 + 3;
+
+/// Size in bytes of a stored [`Eui48`] record.
+pub const EUI48_RECORD_SIZE: usize = 6 + 3;
+/// Size in bytes of a stored [`Eui64`] record.
+pub const EUI64_RECORD_SIZE: usize = 8 + 3;
+
+/// Possible errors while reading or writing an address record.
+#[derive(Debug, PartialEq, Eq)]
+pub enum StorageError<E> {
+    /// Error reported by the underlying storage.
+    Storage(E),
+    /// Record area reads as erased flash, no address was written yet.
+    Erased,
+    /// Magic byte or length doesn't match, the area holds something else.
+    InvalidRecord,
+    /// CRC-8 doesn't match, the record was corrupted or only partly written.
+    ChecksumMismatch,
+    /// Record is intact, but holds the nil or broadcast address, or such an
+    /// address was to be written.
+    InvalidAddress,
+}
+
+impl<E: Display> Display for StorageError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            StorageError::Storage(error) => write!(f, "Storage error: {}", error),
+            StorageError::Erased => f.write_str("No address record was written yet"),
+            StorageError::InvalidRecord => f.write_str("Storage doesn't hold an address record"),
+            StorageError::ChecksumMismatch => f.write_str("Address record is corrupted"),
+            StorageError::InvalidAddress => {
+                f.write_str("Nil and broadcast addresses can't be stored")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: Debug + Display> std::error::Error for StorageError<E> {}
+
+/// CRC-8 with polynomial 0x07.
+fn crc8(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0, |crc, &byte| {
+        (0..8).fold(crc ^ byte, |crc, _| {
+            if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            }
+        })
+    })
+}
+
+/// Nil and broadcast addresses are never assigned to an interface.
+fn is_valid_address(octets: &[u8]) -> bool {
+    !octets.iter().all(|&byte| byte == 0x00) && !octets.iter().all(|&byte| byte == 0xff)
+}
+
+fn read_record<S: ReadStorage>(
+    storage: &mut S,
+    offset: u32,
+    address: &mut [u8],
+) -> Result<(), StorageError<S::Error>> {
+    let mut buffer = [0; MAX_RECORD_SIZE];
+    let record = &mut buffer[..address.len() + 3];
+
+    storage
+        .read(offset, record)
+        .map_err(StorageError::Storage)?;
+
+    if record.iter().all(|&byte| byte == 0xff) {
+        return Err(StorageError::Erased);
+    }
+
+    if record[0] != MAGIC || record[1] as usize != address.len() {
+        return Err(StorageError::InvalidRecord);
+    }
+
+    let (data, checksum) = record.split_at(record.len() - 1);
+
+    if crc8(data) != checksum[0] {
+        return Err(StorageError::ChecksumMismatch);
+    }
+
+    let octets = &data[2..];
+
+    if !is_valid_address(octets) {
+        return Err(StorageError::InvalidAddress);
+    }
+
+    address.copy_from_slice(octets);
+
+    Ok(())
+}
+
+fn write_record<S: Storage>(
+    storage: &mut S,
+    offset: u32,
+    address: &[u8],
+) -> Result<(), StorageError<S::Error>> {
+    if !is_valid_address(address) {
+        return Err(StorageError::InvalidAddress);
+    }
+
+    let mut buffer = [0; MAX_RECORD_SIZE];
+    let record = &mut buffer[..address.len() + 3];
+
+    record[0] = MAGIC;
+    record[1] = address.len() as u8;
+    record[2..address.len() + 2].copy_from_slice(address);
+    record[address.len() + 2] = crc8(&record[..address.len() + 2]);
+
+    storage.write(offset, record).map_err(StorageError::Storage)
+}
+
+/// Reads an [`Eui48`] record written by [`write_mac`] at `offset`.
+pub fn read_mac<S: ReadStorage>(
+    storage: &mut S,
+    offset: u32,
+) -> Result<Eui48, StorageError<S::Error>> {
+    let mut result = [0; 6];
+    read_record(storage, offset, &mut result)?;
+
+    Ok(Eui(result))
+}
+
+/// Writes `eui48` as a record of [`EUI48_RECORD_SIZE`] bytes at `offset`.
+pub fn write_mac<S: Storage>(
+    storage: &mut S,
+    offset: u32,
+    eui48: &Eui48,
+) -> Result<(), StorageError<S::Error>> {
+    write_record(storage, offset, &eui48.0)
+}
+
+/// Reads an [`Eui64`] record written by [`write_eui64`] at `offset`.
+pub fn read_eui64<S: ReadStorage>(
+    storage: &mut S,
+    offset: u32,
+) -> Result<Eui64, StorageError<S::Error>> {
+    let mut result = [0; 8];
+    read_record(storage, offset, &mut result)?;
+
+    Ok(Eui(result))
+}
+
+/// Writes `eui64` as a record of [`EUI64_RECORD_SIZE`] bytes at `offset`.
+pub fn write_eui64<S: Storage>(
+    storage: &mut S,
+    offset: u32,
+    eui64: &Eui64,
+) -> Result<(), StorageError<S::Error>> {
+    write_record(storage, offset, &eui64.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_storage::{ReadStorage, Storage};
+
+    struct Flash([u8; 32]);
+
+    impl ReadStorage for Flash {
+        type Error = ();
+
+        fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), ()> {
+            let offset = offset as usize;
+            let data = self.0.get(offset..offset + bytes.len()).ok_or(())?;
+
+            bytes.copy_from_slice(data);
+            Ok(())
+        }
+
+        fn capacity(&self) -> usize {
+            self.0.len()
+        }
+    }
+
+    impl Storage for Flash {
+        fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), ()> {
+            let offset = offset as usize;
+            let data = self.0.get_mut(offset..offset + bytes.len()).ok_or(())?;
+
+            data.copy_from_slice(bytes);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_mac_round_trip() {
+        let mut flash = Flash([0xff; 32]);
+        let eui48 = Eui48::from(85204980412143);
+
+        write_mac(&mut flash, 4, &eui48).unwrap();
+
+        assert_eq!(
+            flash.0[4..4 + EUI48_RECORD_SIZE],
+            [0xE1, 6, 0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef, 0x4a]
+        );
+        assert_eq!(read_mac(&mut flash, 4), Ok(eui48));
+    }
+
+    #[test]
+    fn test_eui64_round_trip() {
+        let mut flash = Flash([0xff; 32]);
+        let eui64 = Eui64::from(5583992946972634863);
+
+        write_eui64(&mut flash, 0, &eui64).unwrap();
+
+        assert_eq!(read_eui64(&mut flash, 0), Ok(eui64));
+        assert_eq!(read_mac(&mut flash, 0), Err(StorageError::InvalidRecord));
+    }
+
+    #[test]
+    fn test_read_mac_erased() {
+        let mut flash = Flash([0xff; 32]);
+
+        assert_eq!(read_mac(&mut flash, 0), Err(StorageError::Erased));
+    }
+
+    #[test]
+    fn test_read_mac_corrupted() {
+        let mut flash = Flash([0xff; 32]);
+
+        write_mac(&mut flash, 0, &Eui48::from(85204980412143)).unwrap();
+        flash.0[3] ^= 0x01;
+
+        assert_eq!(read_mac(&mut flash, 0), Err(StorageError::ChecksumMismatch));
+    }
+
+    #[test]
+    fn test_read_mac_invalid_address() {
+        let mut flash = Flash([0xff; 32]);
+        let record = [0xE1, 6, 0, 0, 0, 0, 0, 0];
+
+        flash.0[..8].copy_from_slice(&record);
+        flash.0[8] = crc8(&record);
+
+        assert_eq!(read_mac(&mut flash, 0), Err(StorageError::InvalidAddress));
+    }
+
+    #[test]
+    fn test_write_invalid_address() {
+        let mut flash = Flash([0xff; 32]);
+
+        assert_eq!(
+            write_mac(&mut flash, 0, &Eui48::NIL),
+            Err(StorageError::InvalidAddress)
+        );
+        assert_eq!(
+            write_eui64(&mut flash, 0, &Eui64::BROADCAST),
+            Err(StorageError::InvalidAddress)
+        );
+        assert_eq!(flash.0, [0xff; 32]);
+    }
+
+    #[test]
+    fn test_storage_error_display() {
+        use core::fmt::Write;
+
+        let mut string = heapless::String::<64>::new();
+
+        write!(string, "{}", StorageError::Storage("timeout")).unwrap();
+        assert_eq!(string, "Storage error: timeout");
+
+        string.clear();
+        write!(string, "{}", StorageError::<&str>::ChecksumMismatch).unwrap();
+        assert_eq!(string, "Address record is corrupted");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_storage_error_is_std_error() {
+        extern crate std;
+
+        use std::boxed::Box;
+        use std::string::ToString;
+
+        let error: Box<dyn std::error::Error> = Box::new(StorageError::<&str>::InvalidAddress);
+
+        assert_eq!(
+            error.to_string(),
+            "Nil and broadcast addresses can't be stored"
+        );
+    }
+
+    #[test]
+    fn test_read_mac_out_of_bounds() {
+        let mut flash = Flash([0xff; 32]);
+
+        assert_eq!(read_mac(&mut flash, 30), Err(StorageError::Storage(())));
+    }
+}
@@ -0,0 +1,60 @@
+//! Conversions to `alloc` strings for host-side code that doesn't want to deal
+//! with heapless capacities.
+//!
+//! The inherent `to_string()` methods keep returning heapless strings, so
+//! `ToString` has to be called explicitly, or `String::from` used.
+use crate::{Eui48, Eui60, Eui64, LinkLayerAddr, Oui};
+use alloc::string::{String, ToString};
+
+impl From<Eui48> for String {
+    fn from(eui48: Eui48) -> Self {
+        ToString::to_string(&eui48)
+    }
+}
+
+impl From<Eui64> for String {
+    fn from(eui64: Eui64) -> Self {
+        ToString::to_string(&eui64)
+    }
+}
+
+impl From<Eui60> for String {
+    fn from(eui60: Eui60) -> Self {
+        ToString::to_string(&eui60)
+    }
+}
+
+impl From<Oui> for String {
+    fn from(oui: Oui) -> Self {
+        ToString::to_string(&oui)
+    }
+}
+
+impl From<LinkLayerAddr> for String {
+    fn from(address: LinkLayerAddr) -> Self {
+        ToString::to_string(&address)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui64, LinkLayerAddr, Oui};
+    use alloc::string::{String, ToString};
+
+    #[test]
+    fn test_to_alloc_string() {
+        let eui48 = Eui48::from(85204980412143);
+
+        assert_eq!(String::from(eui48), "4D-7E-54-97-2E-EF");
+        assert_eq!(ToString::to_string(&eui48), "4D-7E-54-97-2E-EF");
+        assert_eq!(
+            String::from(Eui64::from(5583992946972634863)),
+            "4D-7E-54-00-00-97-2E-EF"
+        );
+        assert_eq!(String::from(Oui::from(0x4D7E54)), "4D-7E-54");
+        assert_eq!(
+            String::from(LinkLayerAddr::from(eui48)),
+            "4D-7E-54-97-2E-EF"
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use crate::format::Case;
+use crate::{Eui, LinkLayerAddr, Oui};
+use ufmt::{uDebug, uDisplay, uWrite, Formatter};
+
+// Digits go straight to the writer, in the default `Display` form, so no
+// `core::fmt` machinery or buffer ends up in the binary.
+
+fn write_hyphenated<W: uWrite + ?Sized>(
+    f: &mut Formatter<'_, W>,
+    bytes: &[u8],
+) -> Result<(), W::Error> {
+    let hex_chars = Case::Upper.hex_chars();
+
+    for (i, byte) in bytes.iter().enumerate() {
+        if i != 0 {
+            f.write_char('-')?;
+        }
+
+        f.write_char(hex_chars[(byte >> 4) as usize] as char)?;
+        f.write_char(hex_chars[(byte & 0xf) as usize] as char)?;
+    }
+
+    Ok(())
+}
+
+impl<const N: usize> uDisplay for Eui<N> {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        write_hyphenated(f, &self.0)
+    }
+}
+
+impl<const N: usize> uDebug for Eui<N> {
+    /// Same as `uDisplay`, octets as a list would cost more flash than they
+    /// are worth.
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        write_hyphenated(f, &self.0)
+    }
+}
+
+impl uDisplay for Oui {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        write_hyphenated(f, &self.0)
+    }
+}
+
+impl uDebug for Oui {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        write_hyphenated(f, &self.0)
+    }
+}
+
+impl uDisplay for LinkLayerAddr {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match self {
+            LinkLayerAddr::Eui48(eui48) => uDisplay::fmt(eui48, f),
+            LinkLayerAddr::Eui64(eui64) => uDisplay::fmt(eui64, f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eui48, Eui64};
+    use core::convert::Infallible;
+    use heapless::String;
+    use ufmt::{uWrite, uwrite};
+
+    struct Buffer(String<64>);
+
+    impl uWrite for Buffer {
+        type Error = Infallible;
+
+        fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+            self.0.push_str(s).unwrap();
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_ufmt() {
+        let mut buffer = Buffer(String::new());
+        let eui48 = Eui48::from(85204980412143);
+
+        uwrite!(buffer, "{} {:?} {}", eui48, Eui64::from(eui48), eui48.oui()).unwrap();
+
+        assert_eq!(
+            buffer.0,
+            "4D-7E-54-97-2E-EF 4D-7E-54-00-00-97-2E-EF 4D-7E-54"
+        );
+    }
+}
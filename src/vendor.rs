@@ -0,0 +1,292 @@
+//! Vendor names of OUIs, e.g. to print `Espressif` next to a scanned address.
+//!
+//! Any [`OuiRegistry`] can be plugged in. A sorted slice of `(Oui, &str)`
+//! pairs is one, and with the `oui-table` feature [`IEEE_SUBSET`] ships a
+//! small part of the IEEE registry as static data, so no filesystem is needed.
+//!
+//! With the `oui-table-codegen` feature the build script generates
+//! [`IEEE_SUBSET`] instead, from the IEEE MA-L CSV (`oui.csv`) found at the
+//! absolute path in the `EUI_OUI_CSV` environment variable. Nothing is
+//! downloaded; the build fails if the variable is unset or the file doesn't
+//! parse.
+//!
+//! # Example
+//!
+//! ```rust
+//! use eui::vendor::OuiRegistry;
+//! use eui::{Eui48, Oui};
+//!
+//! let registry = [
+//!     (Oui::from(0x00000C), "Cisco"),
+//!     (Oui::from(0x4D7E54), "Example"),
+//! ];
+//! let eui48 = Eui48::from(85204980412143);
+//!
+//! assert_eq!(registry[..].lookup(eui48.oui()), Some("Example"));
+//! ```
+use crate::{Oui, RegistryKind};
+
+/// Source of vendor names, keyed by OUI.
+pub trait OuiRegistry {
+    /// Vendor name of `oui`, `None` if unknown.
+    fn lookup(&self, oui: Oui) -> Option<&str>;
+
+    /// Block size `oui` was assigned in, `None` if unknown.
+    ///
+    /// Defaults to [`MaL`](RegistryKind::MaL) for every known OUI, registries
+    /// covering MA-M and MA-S blocks override it.
+    fn assignment(&self, oui: Oui) -> Option<RegistryKind> {
+        self.lookup(oui).map(|_| RegistryKind::MaL)
+    }
+}
+
+impl OuiRegistry for [(Oui, &str)] {
+    /// Binary search, so the slice has to be sorted by OUI.
+    fn lookup(&self, oui: Oui) -> Option<&str> {
+        self.binary_search_by_key(&oui, |&(oui, _)| oui)
+            .ok()
+            .map(|index| self[index].1)
+    }
+}
+
+impl<R: OuiRegistry + ?Sized> OuiRegistry for &R {
+    fn lookup(&self, oui: Oui) -> Option<&str> {
+        (**self).lookup(oui)
+    }
+
+    fn assignment(&self, oui: Oui) -> Option<RegistryKind> {
+        (**self).assignment(oui)
+    }
+}
+
+/// Registry of sorted OUIs with an index into a list of names per OUI, so
+/// vendors owning many OUIs store their name once.
+///
+/// That is 5 bytes per entry without padding, which keeps static tables
+/// small enough for flash even for the whole registry.
+#[derive(Clone, Copy, Debug)]
+pub struct OuiTable<'a> {
+    ouis: &'a [[u8; 3]],
+    vendors: &'a [u16],
+    names: &'a [&'a str],
+}
+
+impl<'a> OuiTable<'a> {
+    /// Table where `vendors[i]` is the index in `names` of the vendor owning
+    /// `ouis[i]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ouis` and `vendors` differ in length. Unsorted `ouis` or
+    /// indices out of `names` make lookups miss.
+    pub const fn new(ouis: &'a [[u8; 3]], vendors: &'a [u16], names: &'a [&'a str]) -> Self {
+        assert!(ouis.len() == vendors.len());
+
+        OuiTable {
+            ouis,
+            vendors,
+            names,
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.ouis.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.ouis.is_empty()
+    }
+
+    /// Entries in OUI order.
+    pub fn iter(&self) -> impl Iterator<Item = (Oui, &'a str)> + '_ {
+        self.ouis
+            .iter()
+            .zip(self.vendors)
+            .filter_map(move |(&oui, &vendor)| Some((Oui(oui), *self.names.get(vendor as usize)?)))
+    }
+}
+
+impl OuiRegistry for OuiTable<'_> {
+    fn lookup(&self, oui: Oui) -> Option<&str> {
+        let index = self.ouis.binary_search(&oui.0).ok()?;
+
+        self.names.get(self.vendors[index] as usize).copied()
+    }
+}
+
+#[cfg(all(feature = "oui-table", not(feature = "oui-table-codegen")))]
+const NAMES: [&str; 17] = [
+    "Xerox",
+    "Cisco",
+    "Samsung",
+    "Microchip",
+    "Silicon Labs",
+    "Texas Instruments",
+    "Digi International",
+    "Microsoft",
+    "Intel",
+    "VMware",
+    "STMicroelectronics",
+    "Realtek",
+    "Espressif",
+    "Raspberry Pi",
+    "Nest Labs",
+    "Arduino",
+    "Broadcom",
+];
+
+#[cfg(all(feature = "oui-table", not(feature = "oui-table-codegen")))]
+const OUIS: [[u8; 3]; 31] = [
+    [0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x0C],
+    [0x00, 0x00, 0xF0],
+    [0x00, 0x04, 0xA3],
+    [0x00, 0x0B, 0x57],
+    [0x00, 0x0C, 0x29],
+    [0x00, 0x10, 0x18],
+    [0x00, 0x12, 0x4B],
+    [0x00, 0x13, 0xA2],
+    [0x00, 0x15, 0x5D],
+    [0x00, 0x1B, 0x21],
+    [0x00, 0x50, 0x56],
+    [0x00, 0x80, 0xE1],
+    [0x00, 0xE0, 0x4C],
+    [0x18, 0xB4, 0x30],
+    [0x18, 0xFE, 0x34],
+    [0x24, 0x0A, 0xC4],
+    [0x24, 0x6F, 0x28],
+    [0x28, 0xCD, 0xC1],
+    [0x30, 0xAE, 0xA4],
+    [0x3C, 0x71, 0xBF],
+    [0x5C, 0xCF, 0x7F],
+    [0x60, 0x01, 0x94],
+    [0x84, 0xF3, 0xEB],
+    [0xA4, 0xCF, 0x12],
+    [0xA8, 0x61, 0x0A],
+    [0xB8, 0x27, 0xEB],
+    [0xCC, 0x50, 0xE3],
+    [0xD8, 0x3A, 0xDD],
+    [0xDC, 0xA6, 0x32],
+    [0xE4, 0x5F, 0x01],
+];
+
+#[cfg(all(feature = "oui-table", not(feature = "oui-table-codegen")))]
+const VENDORS: [u16; 31] = [
+    0, 1, 2, 3, 4, 9, 16, 5, 6, 7, 8, 9, 10, 11, 14, 12, 12, 12, 13, 12, 12, 12, 12, 12, 12, 15,
+    13, 12, 13, 13, 13,
+];
+
+#[cfg(feature = "oui-table-codegen")]
+include!(concat!(env!("OUT_DIR"), "/oui_table.rs"));
+
+/// Part of the IEEE MA-L registry covering vendors common in embedded and
+/// virtualized networks, with shortened names, or with `oui-table-codegen`
+/// the registry generated from `EUI_OUI_CSV`.
+#[cfg(feature = "oui-table")]
+pub static IEEE_SUBSET: OuiTable<'static> = OuiTable::new(&OUIS, &VENDORS, &NAMES);
+
+#[cfg(test)]
+mod tests {
+    use super::{OuiRegistry, OuiTable};
+    use crate::Oui;
+
+    #[test]
+    fn test_slice_lookup() {
+        let registry = [
+            (Oui::from(0x000000), "Xerox"),
+            (Oui::from(0x00000C), "Cisco"),
+        ];
+
+        assert_eq!(registry[..].lookup(Oui::from(0x00000C)), Some("Cisco"));
+        assert_eq!(registry[..].lookup(Oui::from(0x00000B)), None);
+    }
+
+    #[test]
+    fn test_table_lookup() {
+        let table = OuiTable::new(&[[0, 0, 1], [0, 0, 2], [0, 0, 3]], &[0, 1, 0], &["A", "B"]);
+
+        assert_eq!(table.lookup(Oui::from(3)), Some("A"));
+        assert_eq!(table.lookup(Oui::from(2)), Some("B"));
+        assert_eq!(table.lookup(Oui::from(4)), None);
+        assert_eq!(table.iter().count(), 3);
+    }
+
+    #[test]
+    fn test_assignment_override() {
+        use crate::RegistryKind;
+
+        // OUI of the Registration Authority holding MA-S blocks.
+        struct MaS;
+
+        impl OuiRegistry for MaS {
+            fn lookup(&self, oui: Oui) -> Option<&str> {
+                (oui == Oui::from(0x70B3D5)).then_some("IEEE Registration Authority")
+            }
+
+            fn assignment(&self, oui: Oui) -> Option<RegistryKind> {
+                self.lookup(oui).map(|_| RegistryKind::MaS)
+            }
+        }
+
+        assert_eq!(
+            Oui::from(0x70B3D5).registry_kind_in(&MaS),
+            RegistryKind::MaS
+        );
+        assert_eq!(
+            Oui::from(0x70B3D6).registry_kind_in(&MaS),
+            RegistryKind::Universal
+        );
+    }
+
+    #[cfg(all(feature = "oui-table", not(feature = "oui-table-codegen")))]
+    #[test]
+    fn test_ieee_subset() {
+        use super::IEEE_SUBSET;
+        use crate::Eui48;
+
+        let eui48 = Eui48::from(0x240AC4123456);
+
+        assert_eq!(IEEE_SUBSET.lookup(eui48.oui()), Some("Espressif"));
+        assert_eq!(
+            IEEE_SUBSET.lookup(Oui::from(0xB827EB)),
+            Some("Raspberry Pi")
+        );
+        assert_eq!(IEEE_SUBSET.lookup(Oui::from(0x4D7E54)), None);
+        assert_eq!(IEEE_SUBSET.iter().count(), IEEE_SUBSET.len());
+        assert!(IEEE_SUBSET
+            .iter()
+            .zip(IEEE_SUBSET.iter().skip(1))
+            .all(|((a, _), (b, _))| a < b));
+    }
+
+    /// Expects `EUI_OUI_CSV` to point to `fixtures/oui.csv`.
+    #[cfg(feature = "oui-table-codegen")]
+    #[test]
+    fn test_generated_table() {
+        use super::IEEE_SUBSET;
+
+        assert_eq!(IEEE_SUBSET.len(), 4);
+        assert_eq!(
+            IEEE_SUBSET.lookup(Oui::from(0x00000C)),
+            Some("Cisco Systems, Inc")
+        );
+        assert_eq!(
+            IEEE_SUBSET.lookup(Oui::from(0x240AC4)),
+            Some("Espressif Inc.")
+        );
+        assert_eq!(
+            IEEE_SUBSET.lookup(Oui::from(0x30AEA4)),
+            Some("Espressif Inc.")
+        );
+        assert_eq!(
+            IEEE_SUBSET.lookup(Oui::from(0xB827EB)),
+            Some("Raspberry Pi \"Foundation\"")
+        );
+        // MA-M and MA-S rows are left out.
+        assert_eq!(IEEE_SUBSET.lookup(Oui::from(0x70B3D5)), None);
+        assert!(IEEE_SUBSET
+            .iter()
+            .zip(IEEE_SUBSET.iter().skip(1))
+            .all(|((a, _), (b, _))| a < b));
+    }
+}
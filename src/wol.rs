@@ -0,0 +1,173 @@
+//! Wake-on-LAN magic packets: six `FF` octets followed by the target address
+//! repeated 16 times, optionally followed by a four or six octet SecureOn
+//! password.
+//!
+//! # Example
+//!
+//! ```rust
+//! use eui::wol::{MagicPacket, MAGIC_PACKET_SIZE};
+//! use eui::Eui48;
+//!
+//! let eui48 = Eui48::from(85204980412143);
+//! let mut payload = [0; MAGIC_PACKET_SIZE];
+//!
+//! MagicPacket::new(eui48).write(&mut payload).unwrap();
+//!
+//! assert_eq!(MagicPacket::parse(&payload), Some(eui48));
+//! ```
+use crate::{BufferTooSmall, Eui48};
+use core::convert::TryFrom;
+
+/// Size of a magic packet without password.
+pub const MAGIC_PACKET_SIZE: usize = 6 + 16 * 6;
+
+/// SecureOn password, checked by the NIC before waking up.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SecureOn {
+    FourOctets([u8; 4]),
+    SixOctets([u8; 6]),
+}
+
+impl SecureOn {
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            SecureOn::FourOctets(bytes) => bytes,
+            SecureOn::SixOctets(bytes) => bytes,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MagicPacket {
+    pub target: Eui48,
+    pub password: Option<SecureOn>,
+}
+
+impl MagicPacket {
+    pub const fn new(target: Eui48) -> Self {
+        MagicPacket {
+            target,
+            password: None,
+        }
+    }
+
+    pub const fn with_password(mut self, password: SecureOn) -> Self {
+        self.password = Some(password);
+        self
+    }
+
+    /// Size of the payload written by [`write`](Self::write).
+    pub fn encoded_len(&self) -> usize {
+        MAGIC_PACKET_SIZE + self.password.as_ref().map_or(0, |p| p.as_bytes().len())
+    }
+
+    /// Writes the payload to the start of `buf`, returning its size.
+    pub fn write(&self, buf: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        let len = self.encoded_len();
+
+        if buf.len() < len {
+            return Err(BufferTooSmall);
+        }
+
+        buf[..6].copy_from_slice(&[0xff; 6]);
+
+        for chunk in buf[6..MAGIC_PACKET_SIZE].chunks_exact_mut(6) {
+            chunk.copy_from_slice(self.target.as_bytes());
+        }
+
+        if let Some(password) = &self.password {
+            buf[MAGIC_PACKET_SIZE..len].copy_from_slice(password.as_bytes());
+        }
+
+        Ok(len)
+    }
+
+    /// Target address of a payload holding exactly one magic packet, with or
+    /// without password.
+    pub fn parse(payload: &[u8]) -> Option<Eui48> {
+        MagicPacket::decode(payload).map(|packet| packet.target)
+    }
+
+    /// Like [`parse`](Self::parse), keeping the password.
+    pub fn decode(payload: &[u8]) -> Option<Self> {
+        if payload.len() < MAGIC_PACKET_SIZE || payload[..6] != [0xff; 6] {
+            return None;
+        }
+
+        let (body, password) = payload[6..].split_at(MAGIC_PACKET_SIZE - 6);
+        let target = Eui48::try_from(&body[..6]).ok()?;
+
+        if body.chunks_exact(6).any(|chunk| chunk != target.as_bytes()) {
+            return None;
+        }
+
+        let password = match password.len() {
+            0 => None,
+            4 => Some(SecureOn::FourOctets(<[u8; 4]>::try_from(password).ok()?)),
+            6 => Some(SecureOn::SixOctets(<[u8; 6]>::try_from(password).ok()?)),
+            _ => return None,
+        };
+
+        Some(MagicPacket { target, password })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MagicPacket, SecureOn, MAGIC_PACKET_SIZE};
+    use crate::{BufferTooSmall, Eui48};
+
+    #[test]
+    fn test_magic_packet_write() {
+        let eui48 = Eui48::from(85204980412143);
+        let mut buf = [0; 110];
+
+        assert_eq!(
+            MagicPacket::new(eui48).write(&mut buf),
+            Ok(MAGIC_PACKET_SIZE)
+        );
+        assert_eq!(buf[..6], [0xff; 6]);
+        assert_eq!(buf[6..12], [0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef]);
+        assert_eq!(buf[96..102], [0x4d, 0x7e, 0x54, 0x97, 0x2e, 0xef]);
+        assert_eq!(buf[102], 0);
+    }
+
+    #[test]
+    fn test_magic_packet_write_buffer_too_small() {
+        let mut buf = [0; MAGIC_PACKET_SIZE];
+        let packet = MagicPacket::new(Eui48::from(1)).with_password(SecureOn::FourOctets([1; 4]));
+
+        assert_eq!(packet.encoded_len(), 106);
+        assert_eq!(packet.write(&mut buf), Err(BufferTooSmall));
+    }
+
+    #[test]
+    fn test_magic_packet_round_trip_with_password() {
+        let packet = MagicPacket::new(Eui48::from(85204980412143))
+            .with_password(SecureOn::SixOctets([1, 2, 3, 4, 5, 6]));
+        let mut buf = [0; 108];
+
+        assert_eq!(packet.write(&mut buf), Ok(108));
+        assert_eq!(MagicPacket::decode(&buf), Some(packet));
+        assert_eq!(MagicPacket::parse(&buf), Some(packet.target));
+    }
+
+    #[test]
+    fn test_magic_packet_parse_invalid() {
+        let mut buf = [0; 108];
+        MagicPacket::new(Eui48::from(85204980412143))
+            .write(&mut buf)
+            .unwrap();
+
+        // Password of unsupported length.
+        assert_eq!(MagicPacket::parse(&buf[..104]), None);
+        assert_eq!(MagicPacket::parse(&buf[..101]), None);
+
+        buf[50] ^= 0x01;
+        assert_eq!(MagicPacket::parse(&buf[..MAGIC_PACKET_SIZE]), None);
+
+        buf[50] ^= 0x01;
+        buf[0] = 0;
+        assert_eq!(MagicPacket::parse(&buf[..MAGIC_PACKET_SIZE]), None);
+    }
+}